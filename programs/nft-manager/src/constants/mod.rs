@@ -33,9 +33,95 @@ pub const FINALIZE_MINT_DATA_TAG: &[u8] = b"finmdt";
 #[constant]
 pub const FINALIZE_FRACTIONALIZE_DATA_TAG: &[u8] = b"finfdt";
 
-// TODO: Update this constant
 #[constant]
-pub const MAX_AGE: u64 = 259_200;
+pub const ASSET_CLASS_TAG: &[u8] = b"asclst";
+
+#[constant]
+pub const ORACLE_CONFIG_TAG: &[u8] = b"orcfgt";
+
+#[constant]
+pub const FINALIZE_INDEX_TAG: &[u8] = b"finidx";
+
+#[constant]
+pub const ADMIN_PRICE_TAG: &[u8] = b"admprt";
+
+#[constant]
+pub const PAYMENT_TOKEN_TAG: &[u8] = b"paytkt";
+
+#[constant]
+pub const PAYMENT_VAULT_TAG: &[u8] = b"payvlt";
+
+#[constant]
+pub const GRAM_VAULT_TAG: &[u8] = b"grmvlt";
+
+#[constant]
+pub const GRAM_MINT_TAG: &[u8] = b"grmmnt";
+
+#[constant]
+pub const VAULTED_NFT_TAG: &[u8] = b"vltnft";
+
+#[constant]
+pub const VAULTED_NFT_TOKEN_ACCOUNT_TAG: &[u8] = b"vltnftat";
+
+#[constant]
+pub const LINEAGE_TAG: &[u8] = b"lineag";
+
+#[constant]
+pub const RESERVE_LEDGER_TAG: &[u8] = b"rsvldg";
+
+#[constant]
+pub const ATTESTATION_TAG: &[u8] = b"attest";
+
+#[constant]
+pub const BAR_REGISTRY_TAG: &[u8] = b"barreg";
+
+#[constant]
+pub const BAR_RECORD_TAG: &[u8] = b"barrec";
+
+#[constant]
+pub const REDEMPTION_REQUEST_TAG: &[u8] = b"redeem";
+
+#[constant]
+pub const REDEMPTION_TOKEN_ACCOUNT_TAG: &[u8] = b"rdmtkn";
+
+#[constant]
+pub const BUYBACK_TAG: &[u8] = b"buybck";
+
+pub const MAX_PRICE_UPDATE_OWNERS: usize = 4;
+
+pub const MAX_PENDING_FINALIZATIONS: usize = 64;
+
+pub const MAX_DENOMINATIONS: usize = 16;
+
+/// Collection ids are used as PDA seeds, the empty id being the default collection
+pub const MAX_COLLECTION_ID_LEN: usize = 32;
+
+/// Bar serials are used as PDA seeds
+pub const MAX_BAR_SERIAL_LEN: usize = 32;
+
+pub const MAX_REFINER_LEN: usize = 32;
+
+pub const MAX_VAULT_LOCATION_LEN: usize = 64;
+
+/// Seconds after which anyone may crank a pending finalization
+#[constant]
+pub const FINALIZE_EXPIRY_SECONDS: i64 = 600;
+
+#[constant]
+pub const MAX_BATCH_MINT_SIZE: u8 = 10;
+
+#[constant]
+pub const MAX_FRACTIONALIZE_PARTS: u8 = 10;
+
+#[constant]
+pub const MAX_MERGE_SIZE: u8 = 10;
+
+#[constant]
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Buyback limits reset every UTC day
+#[constant]
+pub const SECONDS_PER_DAY: i64 = 86_400;
 
 #[constant]
 pub const WEIGHT_KEY: &str = "weight";
@@ -43,12 +129,45 @@ pub const WEIGHT_KEY: &str = "weight";
 #[constant]
 pub const DISCRIMINANT_KEY: &str = "discriminant";
 
+#[constant]
+pub const FINENESS_KEY: &str = "fineness";
+
+/// Fineness is expressed in parts per ten thousand, 9999 being 999.9 fine
+#[constant]
+pub const MAX_FINENESS: u16 = 10_000;
+
+/// NFT weights are expressed in milligrams
+#[constant]
+pub const MICROGRAMS_PER_MILLIGRAM: u64 = 1_000;
+
+#[constant]
+pub const TROY_OUNCE_MICROGRAMS: u64 = 31_103_477;
+
+/// NFTs minted before fineness was recorded carry their weight in 1/283 troy ounce
+#[constant]
+pub const LEGACY_WEIGHT_UNITS_PER_TROY_OUNCE: u64 = 283;
+
+#[constant]
+pub const METAL_KEY: &str = "metal";
+
 #[constant]
 pub const COLLECTION_KEY: &str = "collection";
 
 #[constant]
-pub const SOL_PRICE_FEED_ID_HEX: &str =
-    "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+pub const PARENT_KEY: &str = "parent";
+
+#[constant]
+pub const GENERATION_KEY: &str = "generation";
+
+#[constant]
+pub const ROOT_KEY: &str = "root";
+
+#[constant]
+pub const BAR_SERIAL_KEY: &str = "bar_serial";
+
+/// Gram tokens count milligrams, so three decimals make one token a gram
+#[constant]
+pub const GRAM_DECIMALS: u8 = 3;
 
 #[constant]
 pub const LIST_NFT_PRICE_DECIMALS: u8 = 2;
@@ -38,4 +38,86 @@ pub enum NFTManagerError {
     OnlyFutureAuthorityAllowed,
     #[msg("No future authority")]
     NoFutureAuthority,
+    #[msg("Invalid oracle config")]
+    InvalidOracleConfig,
+    #[msg("Price update account not owned by an allowed program")]
+    InvalidPriceUpdateOwner,
+    #[msg("Price confidence interval too wide")]
+    PriceConfidenceTooWide,
+    #[msg("Invalid asset class")]
+    InvalidAssetClass,
+    #[msg("Asset class disabled")]
+    AssetClassDisabled,
+    #[msg("Metal mismatch")]
+    MetalMismatch,
+    #[msg("Invalid fineness")]
+    InvalidFineness,
+    #[msg("Fineness mismatch")]
+    FinenessMismatch,
+    #[msg("Price exceeds max lamports")]
+    SlippageExceeded,
+    #[msg("Payment token disabled")]
+    PaymentTokenDisabled,
+    #[msg("Invalid fee")]
+    InvalidFee,
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+    #[msg("Mint does not match the next discriminant")]
+    InvalidMint,
+    #[msg("Invalid admin price")]
+    InvalidAdminPrice,
+    #[msg("Price is too old")]
+    StalePrice,
+    #[msg("Secondary price unavailable")]
+    SecondaryPriceUnavailable,
+    #[msg("Primary and secondary prices deviate too much")]
+    PriceDeviationTooWide,
+    #[msg("Finalization not overdue yet")]
+    FinalizeNotOverdue,
+    #[msg("Invalid number of parts")]
+    InvalidPartsCount,
+    #[msg("Lineage does not match the mint")]
+    InvalidLineage,
+    #[msg("Weight is below the minimum")]
+    WeightBelowMinimum,
+    #[msg("Weight is above the maximum")]
+    WeightAboveMaximum,
+    #[msg("Weight is not an allowed denomination")]
+    WeightNotDenomination,
+    #[msg("Fractionalization already partly finalized")]
+    FractionalizeAlreadyFinalized,
+    #[msg("Invalid collection id")]
+    InvalidCollectionId,
+    #[msg("Only auditor allowed")]
+    OnlyAuditorAllowed,
+    #[msg("Invalid attestation")]
+    InvalidAttestation,
+    #[msg("Attestation is too old")]
+    StaleAttestation,
+    #[msg("Minting would exceed the attested reserve")]
+    ReserveExceeded,
+    #[msg("Only operator allowed")]
+    OnlyOperatorAllowed,
+    #[msg("Invalid bar")]
+    InvalidBar,
+    #[msg("Bar is not available")]
+    BarUnavailable,
+    #[msg("Bar does not match the NFT")]
+    BarMismatch,
+    #[msg("Redemption already requested")]
+    RedemptionAlreadyRequested,
+    #[msg("Redemption is not pending")]
+    RedemptionNotPending,
+    #[msg("Invalid redemption owner")]
+    InvalidRedemptionOwner,
+    #[msg("Daily buyback limit reached")]
+    BuybackLimitExceeded,
+    #[msg("No reserve recorded for this collection and metal")]
+    ReserveEntryNotFound,
+    #[msg("Bar is split across several NFTs")]
+    BarSplit,
+    #[msg("Only pieces of the same original can be merged")]
+    MixedLineage,
+    #[msg("NFT is held in escrow")]
+    NFTEscrowed,
 }
@@ -22,14 +22,14 @@ pub struct AdminWithdrawFees<'info> {
 
     #[account(
         mut,
-        seeds = [FEES_COLLECTOR_TAG],
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     pub fees_collector: Account<'info, FeesCollector>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Account<'info, NFTManager>,
 }
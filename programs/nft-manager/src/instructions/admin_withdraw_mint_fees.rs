@@ -22,14 +22,14 @@ pub struct AdminWithdrawMintFees<'info> {
 
     #[account(
         mut,
-        seeds = [MINT_FEES_COLLECTOR_TAG],
+        seeds = [MINT_FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     pub mint_fees_collector: Account<'info, MintFeesCollector>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Account<'info, NFTManager>,
 }
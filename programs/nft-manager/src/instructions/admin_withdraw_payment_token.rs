@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    constants::{NFT_MANAGER_TAG, PAYMENT_TOKEN_TAG, PAYMENT_VAULT_TAG},
+    errors::NFTManagerError,
+    states::{nft_manager::NFTManager, payment_token::PaymentToken},
+};
+
+#[derive(Accounts)]
+pub struct AdminWithdrawPaymentToken<'info> {
+    #[account(
+        address = payment_token.token_program @NFTManagerError::InvalidTokenAccount,
+    )]
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        address = payment_token.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [PAYMENT_TOKEN_TAG, mint.key().as_ref()],
+        bump = payment_token.bump,
+    )]
+    pub payment_token: Box<Account<'info, PaymentToken>>,
+
+    #[account(
+        mut,
+        seeds = [PAYMENT_VAULT_TAG, mint.key().as_ref()],
+        bump = payment_token.vault_bump,
+    )]
+    pub vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> AdminWithdrawPaymentToken<'info> {
+    pub fn withdraw_payment_token(&mut self, amount: u64) -> Result<()> {
+        require_gte!(
+            self.vault.amount,
+            amount,
+            NFTManagerError::InsufficientFunds
+        );
+
+        let mint_key = self.mint.key();
+        let vault_seeds = &[
+            PAYMENT_VAULT_TAG,
+            mint_key.as_ref(),
+            &[self.payment_token.vault_bump],
+        ];
+        let vault_signer_seeds = &[&vault_seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    mint: self.mint.to_account_info(),
+                    from: self.vault.to_account_info(),
+                    to: self.recipient_token_account.to_account_info(),
+                    authority: self.vault.to_account_info(),
+                },
+                vault_signer_seeds,
+            ),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+use anchor_lang::{prelude::*, system_program as system};
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022};
+
+use crate::{
+    constants::{
+        ASSET_CLASS_TAG, ATTESTATION_TAG, BAR_RECORD_TAG, BAR_SERIAL_KEY, COLLECTION_TAG,
+        FEES_COLLECTOR_TAG, MAX_BATCH_MINT_SIZE, MINT_FEES_COLLECTOR_TAG, MINT_TAG,
+        NFT_MANAGER_TAG, ORACLE_CONFIG_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        attestation::Attestation,
+        bar_record::BarRecord,
+        fees_collector::FeesCollector,
+        mint_fees_collector::MintFeesCollector,
+        nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+        reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+use super::NFTPartArgs;
+
+/// Remaining accounts are passed as `[mint, recipient_token_account, bar_record]` triples, one
+/// per item, with mints derived from consecutive discriminants.
+#[derive(Accounts)]
+pub struct BatchMintNFT<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: This account will receive the tokens
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MINT_FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub mint_fees_collector: Box<Account<'info, MintFeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, asset_class.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    #[account(
+        seeds = [ATTESTATION_TAG, asset_class.metal.seed()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+}
+
+impl<'info> BatchMintNFT<'info> {
+    pub fn batch_mint_nft(
+        &mut self,
+        bumps: &BatchMintNFTBumps,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        args: BatchMintNFTArgs,
+    ) -> Result<()> {
+        let signer = self.signer.to_account_info();
+        let system_program = self.system_program.to_account_info();
+        let token_program = self.token_program.to_account_info();
+        let metal = self.asset_class.metal;
+        let count = args.items.len();
+
+        require!(
+            count > 0 && count <= MAX_BATCH_MINT_SIZE as usize,
+            NFTManagerError::InvalidBatchSize
+        );
+        require_eq!(
+            remaining_accounts.len(),
+            count * 3,
+            NFTManagerError::InvalidBatchSize
+        );
+        let metal_price = utils::get_metal_price(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+        )?;
+        let sol_price = utils::get_sol_price(
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+        )?;
+
+        let mut weights = Vec::with_capacity(count);
+        let mut prices = Vec::with_capacity(count);
+        let mut price_in_lamports: u64 = 0;
+
+        for item in args.items.iter() {
+            require!(item.metal == metal, NFTManagerError::MetalMismatch);
+
+            let weight = utils::Weight::new(item.weight, item.fineness)?;
+            self.asset_class.validate_weight(weight.milligrams)?;
+            let price = utils::calc_metal_value_in_lamport(
+                metal_price,
+                sol_price,
+                &weight,
+                self.asset_class.price_unit_micrograms,
+            )?;
+
+            price_in_lamports = price_in_lamports
+                .checked_add(price)
+                .ok_or(NFTManagerError::Overflow)?;
+            weights.push(weight);
+            prices.push(price);
+        }
+
+        let premium = self.fees_collector.calc_mint_premium(price_in_lamports)?;
+        let fabrication_fee = self
+            .fees_collector
+            .fabrication_fee
+            .checked_mul(count as u64)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        let total = price_in_lamports
+            .checked_add(premium)
+            .and_then(|v| v.checked_add(fabrication_fee))
+            .ok_or(NFTManagerError::Overflow)?;
+
+        require_gte!(args.max_lamports, total, NFTManagerError::SlippageExceeded);
+
+        system::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system::Transfer {
+                    from: signer.clone(),
+                    to: self.mint_fees_collector.to_account_info(),
+                },
+            ),
+            price_in_lamports,
+        )?;
+
+        let fees = premium
+            .checked_add(fabrication_fee)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        if fees > 0 {
+            system::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system::Transfer {
+                        from: signer.clone(),
+                        to: self.fees_collector.to_account_info(),
+                    },
+                ),
+                fees,
+            )?;
+        }
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        for (i, item) in args.items.into_iter().enumerate() {
+            let mint = &remaining_accounts[i * 3];
+            let recipient_token_account = &remaining_accounts[i * 3 + 1];
+            let mut bar_record = Account::<BarRecord>::try_from(&remaining_accounts[i * 3 + 2])?;
+            let bar_record_key = Pubkey::create_program_address(
+                &[
+                    BAR_RECORD_TAG,
+                    bar_record.serial.as_bytes(),
+                    &[bar_record.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| NFTManagerError::InvalidBar)?;
+            require_keys_eq!(
+                bar_record.key(),
+                bar_record_key,
+                NFTManagerError::InvalidBar
+            );
+            let discriminant = self.nft_manager.discriminant;
+
+            let (mint_key, mint_bump) = Pubkey::find_program_address(
+                &[
+                    MINT_TAG,
+                    collection_id.as_bytes(),
+                    &discriminant.to_le_bytes(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(mint.key(), mint_key, NFTManagerError::InvalidMint);
+
+            let mint_seeds = &[
+                MINT_TAG,
+                collection_id.as_bytes(),
+                &discriminant.to_le_bytes()[..],
+                &[mint_bump],
+            ];
+            let mint_signer_seeds = &[&mint_seeds[..]];
+
+            utils::create_nft_mint(
+                mint.clone(),
+                token_program.clone(),
+                system_program.clone(),
+                signer.clone(),
+                mint_signer_seeds,
+            )?;
+
+            anchor_spl::associated_token::create_idempotent(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                anchor_spl::associated_token::Create {
+                    payer: signer.clone(),
+                    associated_token: recipient_token_account.clone(),
+                    authority: self.recipient.to_account_info(),
+                    mint: mint.clone(),
+                    system_program: system_program.clone(),
+                    token_program: token_program.clone(),
+                },
+            ))?;
+
+            utils::mint_nft_with_metadata(
+                item.name,
+                item.symbol,
+                item.uri,
+                &weights[i],
+                item.metal,
+                discriminant,
+                mint_signer_seeds,
+                &mut self.nft_manager,
+                mint.clone(),
+                recipient_token_account.clone(),
+                self.collection.to_account_info(),
+                bumps.collection,
+                token_program.clone(),
+                system_program.clone(),
+                signer.clone(),
+            )?;
+
+            bar_record.assign(metal, &weights[i])?;
+            utils::update_additional_metadata(
+                mint.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                signer.clone(),
+                vec![(BAR_SERIAL_KEY.to_string(), bar_record.serial.clone())],
+                mint_signer_seeds,
+            )?;
+            bar_record.exit(&crate::ID)?;
+
+            self.reserve_ledger.record_mint(
+                self.nft_manager.collection,
+                metal,
+                weights[i].milligrams,
+            )?;
+
+            emit!(BatchMintNFTEvent {
+                mint: mint_key,
+                recipient: self.recipient.key(),
+                price: prices[i],
+                discriminant,
+                metal,
+                weight: weights[i].milligrams,
+                fineness: weights[i].fineness,
+            });
+        }
+
+        self.attestation
+            .validate_reserve(self.reserve_ledger.outstanding_weight_of(metal)?)?;
+
+        utils::realloc_account(
+            self.reserve_ledger.to_account_info(),
+            signer,
+            system_program,
+            8 + ReserveLedger::calculate_space(self.reserve_ledger.collections.len()),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BatchMintNFTArgs {
+    pub items: Vec<NFTPartArgs>,
+    /// Most lamports the signer accepts to pay for the whole batch
+    pub max_lamports: u64,
+}
+
+#[event]
+pub struct BatchMintNFTEvent {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub price: u64,
+    pub discriminant: u64,
+    pub metal: Metal,
+    pub weight: u64,
+    pub fineness: u16,
+}
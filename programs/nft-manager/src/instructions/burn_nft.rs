@@ -2,9 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token_2022::Token2022, token_interface};
 
 use crate::{
-    constants::{MINT_TAG, NFT_MANAGER_TAG},
+    constants::{ASSET_CLASS_TAG, BAR_RECORD_TAG, MINT_TAG, NFT_MANAGER_TAG, RESERVE_LEDGER_TAG},
     errors::NFTManagerError,
-    states::nft_manager::NFTManager,
+    states::{
+        asset_class::AssetClass, bar_record::BarRecord, nft_manager::NFTManager,
+        reserve_ledger::ReserveLedger,
+    },
     utils,
 };
 
@@ -22,7 +25,7 @@ pub struct BurnNFT<'info> {
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
         bump,
         constraint = mint.supply > 0 @NFTManagerError::InvalidMintSupply,
     )]
@@ -38,10 +41,31 @@ pub struct BurnNFT<'info> {
     pub token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, asset_class.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    /// Required when the NFT carries a bar serial
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, bar_record.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Option<Box<Account<'info, BarRecord>>>,
 }
 
 impl<'info> BurnNFT<'info> {
@@ -52,7 +76,13 @@ impl<'info> BurnNFT<'info> {
         let source = self.token_account.to_account_info();
         let amount = self.mint.supply;
 
-        let mint_seeds = &[MINT_TAG, &discriminant.to_le_bytes(), &[bumps.mint]];
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes(),
+            &[bumps.mint],
+        ];
         let mint_signer_seeds = &[&mint_seeds[..]];
 
         // Get and update the token metadata
@@ -60,7 +90,25 @@ impl<'info> BurnNFT<'info> {
 
         let weight = utils::get_weight(&token_metadata.additional_metadata)?;
 
-        require_gt!(weight, 0, NFTManagerError::InvalidWeight);
+        require_gt!(weight.milligrams, 0, NFTManagerError::InvalidWeight);
+
+        utils::validate_metal(&token_metadata.additional_metadata, self.asset_class.metal)?;
+
+        if let Some(serial) = utils::get_bar_serial(&token_metadata.additional_metadata) {
+            let bar_record = self
+                .bar_record
+                .as_mut()
+                .ok_or(NFTManagerError::BarMismatch)?;
+            require!(bar_record.serial == serial, NFTManagerError::BarMismatch);
+
+            bar_record.release(&weight)?;
+        }
+
+        self.reserve_ledger.record_burn(
+            self.nft_manager.collection,
+            self.asset_class.metal,
+            weight.milligrams,
+        )?;
 
         token_interface::burn(
             CpiContext::new(
@@ -1,18 +1,17 @@
-use anchor_lang::{
-    prelude::*, solana_program::native_token::LAMPORTS_PER_SOL, system_program as system,
-};
+use anchor_lang::{prelude::*, system_program as system};
 use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
 
 use crate::{
     constants::{
-        FEES_COLLECTOR_TAG, LISTING_TAG, LISTING_TOKEN_ACCOUNT_TAG, LIST_NFT_PRICE_DECIMALS,
-        MAX_AGE, MINT_TAG, NFT_MANAGER_TAG, SOL_PRICE_FEED_ID_HEX, USER_TAG,
+        FEES_COLLECTOR_TAG, LISTING_TAG, LISTING_TOKEN_ACCOUNT_TAG, MINT_TAG, NFT_MANAGER_TAG,
+        ORACLE_CONFIG_TAG, USER_TAG,
     },
     errors::NFTManagerError,
     states::{
-        fees_collector::FeesCollector, listing::Listing, nft_manager::NFTManager, user::User,
+        fees_collector::FeesCollector, listing::Listing, nft_manager::NFTManager,
+        oracle_config::OracleConfig, user::User,
     },
+    utils,
 };
 
 #[derive(Accounts)]
@@ -22,7 +21,10 @@ pub struct BuyNFT<'info> {
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    pub sol_price_update: Box<Account<'info, PriceUpdateV2>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub buyer: Signer<'info>,
@@ -35,7 +37,7 @@ pub struct BuyNFT<'info> {
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
         bump,
     )]
     pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
@@ -78,16 +80,22 @@ pub struct BuyNFT<'info> {
 
     #[account(
         mut,
-        seeds = [FEES_COLLECTOR_TAG],
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     pub fees_collector: Box<Account<'info, FeesCollector>>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
 }
 
 impl<'info> BuyNFT<'info> {
@@ -99,47 +107,19 @@ impl<'info> BuyNFT<'info> {
         let listing = self.listing.to_account_info();
         let listing_token_account = self.listing_token_account.to_account_info();
 
-        let price = self.listing.price as u128;
-        let fees_percentage = self.fees_collector.sell_fee;
-        let fees_decimals = self.fees_collector.fees_decimals;
-
-        let sol_feed_id: [u8; 32] = get_feed_id_from_hex(SOL_PRICE_FEED_ID_HEX)?;
-        let sol_price =
-            self.sol_price_update
-                .get_price_no_older_than(&Clock::get()?, MAX_AGE, &sol_feed_id)?;
-
-        require_gt!(sol_price.price, 0, NFTManagerError::NegativePrice);
-
-        let mut numerator = price
-            .checked_mul(LAMPORTS_PER_SOL as u128)
-            .ok_or(NFTManagerError::Overflow)?;
-        let mut denominator = (sol_price.price as u128)
-            .checked_sub(sol_price.conf as u128)
-            .ok_or(NFTManagerError::Overflow)?;
-
-        let exponent_diff = -(LIST_NFT_PRICE_DECIMALS as i32) - sol_price.exponent;
-
-        if exponent_diff < 0 {
-            denominator = denominator
-                .checked_mul(10u128.pow(exponent_diff as u32))
-                .ok_or(NFTManagerError::Overflow)?;
-        } else {
-            numerator = numerator
-                .checked_mul(10u128.pow(exponent_diff as u32))
-                .ok_or(NFTManagerError::Overflow)?;
-        }
-
-        let price_in_lamports: u64 = numerator
-            .checked_div(denominator)
-            .ok_or(NFTManagerError::Overflow)?
-            .try_into()
-            .map_err(|_| NFTManagerError::Overflow)?;
-
-        let fees = price_in_lamports
-            .checked_mul(fees_percentage as u64)
-            .ok_or(NFTManagerError::Overflow)?
-            .checked_div(10u64.pow(fees_decimals as u32))
-            .ok_or(NFTManagerError::Overflow)?;
+        let sol_price = utils::get_sol_price(
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+        )?;
+
+        let price_in_lamports = utils::calc_usd_value_in_lamport(self.listing.price, sol_price)?;
+
+        let fees = self
+            .fees_collector
+            .calc_fee(price_in_lamports, self.fees_collector.sell_fee)?;
 
         system::transfer(
             CpiContext::new(
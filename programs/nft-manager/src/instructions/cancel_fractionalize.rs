@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{self, spl_token_metadata_interface::state::Field},
+};
+
+use crate::{
+    constants::{
+        COLLECTION_KEY, COLLECTION_TAG, FEES_COLLECTOR_TAG, FINALIZE_FRACTIONALIZE_DATA_TAG,
+        FINALIZE_INDEX_TAG, FINENESS_KEY, LINEAGE_TAG, LISTING_TAG, MINT_TAG, NFT_MANAGER_TAG,
+        REDEMPTION_REQUEST_TAG, VAULTED_NFT_TAG, WEIGHT_KEY,
+    },
+    errors::NFTManagerError,
+    states::{
+        fees_collector::FeesCollector,
+        finalize_fractionalize_data::FinalizeFractionalizeData,
+        finalize_index::FinalizeIndex,
+        lineage::Lineage,
+        nft_manager::NFTManager,
+        redemption_request::{RedemptionRequest, RedemptionStatus},
+    },
+    utils,
+};
+
+/// Restores a fractionalized NFT to its original weight and metadata before any other part is minted.
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct CancelFractionalize<'info> {
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = finalize_data.payer @NFTManagerError::InvalidFinalizeData
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+        address = finalize_data.mint @NFTManagerError::MintFinalizeDataMismatch
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+        constraint = token_account.amount == mint.supply @NFTManagerError::InvalidTokenAccount,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [LISTING_TAG, mint.key().as_ref(), signer.key().as_ref()],
+        bump,
+        constraint = listing.data_is_empty() @NFTManagerError::NFTEscrowed,
+    )]
+    /// CHECK: Only exists while the NFT is listed
+    pub listing: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [VAULTED_NFT_TAG, mint.key().as_ref()],
+        bump,
+        constraint = vaulted_nft.data_is_empty() @NFTManagerError::NFTEscrowed,
+    )]
+    /// CHECK: Only exists while the NFT is in a gram vault
+    pub vaulted_nft: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [REDEMPTION_REQUEST_TAG, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Deserialized when it exists, the NFT is escrowed while the request is pending
+    pub redemption_request: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [FINALIZE_FRACTIONALIZE_DATA_TAG, mint.key().as_ref()],
+        bump,
+    )]
+    pub finalize_data: Box<Account<'info, FinalizeFractionalizeData>>,
+
+    #[account(
+        mut,
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        mut,
+        seeds = [LINEAGE_TAG, finalize_data.provenance.root.as_ref()],
+        bump = lineage.bump,
+    )]
+    pub lineage: Box<Account<'info, Lineage>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> CancelFractionalize<'info> {
+    pub fn cancel_fractionalize(
+        &mut self,
+        bumps: &CancelFractionalizeBumps,
+        discriminant: u64,
+    ) -> Result<()> {
+        if !self.redemption_request.data_is_empty() {
+            let redemption_request = RedemptionRequest::try_deserialize(
+                &mut &self.redemption_request.try_borrow_data()?[..],
+            )?;
+            require!(
+                redemption_request.status != RedemptionStatus::Pending,
+                NFTManagerError::NFTEscrowed
+            );
+        }
+
+        let mint = self.mint.to_account_info();
+        let token_metadata = utils::get_metadata(&mint)?;
+        let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+        let original = self.finalize_data.original.clone();
+
+        // Every other part must still be pending, otherwise restoring would duplicate weight
+        let total = weight
+            .milligrams
+            .checked_add(self.finalize_data.pending_weight()?)
+            .ok_or(NFTManagerError::Overflow)?;
+        require_eq!(
+            total,
+            original.weight,
+            NFTManagerError::FractionalizeAlreadyFinalized
+        );
+
+        let original_weight = utils::Weight::new(original.weight, weight.fineness)?;
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes(),
+            &[bumps.mint],
+        ];
+        let mint_signer_seeds = &[&mint_seeds[..]];
+
+        utils::update_metadata_fields(
+            mint,
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            vec![
+                (
+                    Field::Key(WEIGHT_KEY.to_string()),
+                    original_weight.milligrams.to_string(),
+                ),
+                (
+                    Field::Key(FINENESS_KEY.to_string()),
+                    original_weight.fineness.to_string(),
+                ),
+                (Field::Name, original.name),
+                (Field::Symbol, original.symbol),
+                (Field::Uri, original.uri),
+                (
+                    Field::Key(COLLECTION_KEY.to_string()),
+                    self.collection.key().to_string(),
+                ),
+            ],
+            mint_signer_seeds,
+        )?;
+
+        self.lineage
+            .update_weight(self.mint.key(), original_weight.milligrams)?;
+        self.finalize_index.remove(&self.finalize_data.key());
+
+        // Withdrawn fees leave less to refund, the collector stays rent exempt
+        let rent_exempt_balance =
+            Rent::get()?.minimum_balance(self.fees_collector.to_account_info().data_len());
+        let available = self
+            .fees_collector
+            .get_lamports()
+            .saturating_sub(rent_exempt_balance);
+        let refund = self
+            .fees_collector
+            .calc_cancel_refund(self.finalize_data.fee)?
+            .min(available);
+
+        if refund > 0 {
+            self.fees_collector.sub_lamports(refund)?;
+            self.signer.add_lamports(refund)?;
+        }
+
+        emit!(CancelFractionalizeEvent {
+            mint: self.mint.key(),
+            finalize_data: self.finalize_data.key(),
+            owner: self.signer.key(),
+            weight: original_weight.milligrams,
+            refund,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct CancelFractionalizeEvent {
+    pub mint: Pubkey,
+    pub finalize_data: Pubkey,
+    pub owner: Pubkey,
+    pub weight: u64,
+    pub refund: u64,
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        COLLECTION_TAG, FINALIZE_EXPIRY_SECONDS, FINALIZE_FRACTIONALIZE_DATA_TAG,
+        FINALIZE_INDEX_TAG, LINEAGE_TAG, MINT_TAG, NFT_MANAGER_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        finalize_fractionalize_data::FinalizeFractionalizeData, finalize_index::FinalizeIndex,
+        lineage::Lineage, nft_manager::NFTManager, reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+use super::CrankFinalizeEvent;
+
+/// Mints the next part of an overdue fractionalization to the owner of the original NFT.
+/// Anyone may call it, the rent of the finalize account goes back to that owner once all parts are minted.
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct CrankFinalizeFractionalize<'info> {
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Checked against the finalize data
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+        address = finalize_data.mint @NFTManagerError::MintFinalizeDataMismatch
+    )]
+    /// CHECK: The mint account to finalize
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &nft_manager.discriminant.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = new_mint,
+        mint::freeze_authority = new_mint,
+        extensions::metadata_pointer::metadata_address = new_mint,
+        extensions::metadata_pointer::authority = new_mint,
+        extensions::group_member_pointer::authority = new_mint,
+        extensions::group_member_pointer::member_address = new_mint,
+        extensions::close_authority::authority = new_mint,
+    )]
+    pub new_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = new_mint,
+        associated_token::authority = payer,
+        associated_token::token_program = token_program,
+    )]
+    pub new_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_FRACTIONALIZE_DATA_TAG, mint.key().as_ref()],
+        bump,
+        has_one = payer @NFTManagerError::InvalidFinalizeData,
+    )]
+    pub finalize_data: Box<Account<'info, FinalizeFractionalizeData>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        mut,
+        seeds = [LINEAGE_TAG, finalize_data.provenance.root.as_ref()],
+        bump = lineage.bump,
+    )]
+    pub lineage: Box<Account<'info, Lineage>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+}
+
+impl<'info> CrankFinalizeFractionalize<'info> {
+    pub fn crank_finalize_fractionalize(
+        &mut self,
+        bumps: &CrankFinalizeFractionalizeBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            now.saturating_sub(self.finalize_data.created_at) >= FINALIZE_EXPIRY_SECONDS,
+            NFTManagerError::FinalizeNotOverdue
+        );
+
+        let part = self
+            .finalize_data
+            .next_part()
+            .ok_or(NFTManagerError::InvalidFinalizeData)?;
+
+        let new_mint_discriminant = self.nft_manager.discriminant;
+        let weight = utils::Weight::new(part.weight, self.finalize_data.fineness)?;
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let new_mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &new_mint_discriminant.to_le_bytes(),
+            &[bumps.new_mint],
+        ];
+        let new_mint_signer_seeds = &[&new_mint_seeds[..]];
+
+        utils::mint_nft(
+            part.name,
+            part.symbol,
+            part.uri,
+            weight.milligrams,
+            self.finalize_data.metal,
+            new_mint_discriminant,
+            new_mint_signer_seeds,
+            &mut self.nft_manager,
+            self.new_mint.to_account_info(),
+            self.new_token_account.to_account_info(),
+            self.collection.to_account_info(),
+            bumps.collection,
+            self.token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            self.signer.to_account_info(),
+        )?;
+
+        utils::update_metadata_standard(
+            self.new_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            self.collection.key(),
+            &weight,
+            new_mint_signer_seeds,
+        )?;
+
+        let provenance = self.finalize_data.provenance;
+        let mut fields = utils::provenance_metadata(&provenance);
+        fields.extend(utils::bar_serial_metadata(
+            self.finalize_data.bar_serial.as_deref(),
+        ));
+        utils::update_additional_metadata(
+            self.new_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            fields,
+            new_mint_signer_seeds,
+        )?;
+
+        self.lineage
+            .record(self.new_mint.key(), &provenance, weight.milligrams)?;
+        utils::realloc_account(
+            self.lineage.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + Lineage::calculate_space(self.lineage.entries.len()),
+        )?;
+
+        self.reserve_ledger
+            .record_split(self.nft_manager.collection, self.finalize_data.metal)?;
+
+        if self.finalize_data.parts.is_empty() {
+            self.finalize_index.remove(&self.finalize_data.key());
+            self.finalize_data.close(self.payer.to_account_info())?;
+        }
+
+        emit!(CrankFinalizeEvent {
+            finalize_data: self.finalize_data.key(),
+            mint: self.new_mint.key(),
+            payer: self.payer.key(),
+            cranker: self.signer.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        COLLECTION_TAG, FINALIZE_EXPIRY_SECONDS, FINALIZE_INDEX_TAG, FINALIZE_MINT_DATA_TAG,
+        MINT_TAG, NFT_MANAGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        finalize_index::FinalizeIndex, finalize_mint_data::FinalizeMintData,
+        nft_manager::NFTManager,
+    },
+    utils,
+};
+
+/// Completes a mint whose finalization is overdue. Anyone may call it, the rent of the finalize
+/// account goes back to whoever paid for it.
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct CrankFinalizeMint<'info> {
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Checked against the finalize data
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [FINALIZE_MINT_DATA_TAG, mint.key().as_ref()],
+        bump,
+        has_one = mint @NFTManagerError::InvalidFinalizeData,
+        has_one = payer @NFTManagerError::InvalidFinalizeData,
+    )]
+    pub finalize_data: Box<Account<'info, FinalizeMintData>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> CrankFinalizeMint<'info> {
+    pub fn crank_finalize_mint(
+        &mut self,
+        bumps: &CrankFinalizeMintBumps,
+        discriminant: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            now.saturating_sub(self.finalize_data.created_at) >= FINALIZE_EXPIRY_SECONDS,
+            NFTManagerError::FinalizeNotOverdue
+        );
+
+        let weight = utils::Weight::new(self.finalize_data.weight, self.finalize_data.fineness)?;
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes()[..],
+            &[bumps.mint],
+        ];
+        let mint_signer_seeds = &[&mint_seeds[..]];
+
+        utils::update_metadata_standard(
+            self.mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            self.collection.key(),
+            &weight,
+            mint_signer_seeds,
+        )?;
+
+        self.finalize_index.remove(&self.finalize_data.key());
+
+        emit!(CrankFinalizeEvent {
+            finalize_data: self.finalize_data.key(),
+            mint: self.mint.key(),
+            payer: self.payer.key(),
+            cranker: self.signer.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct CrankFinalizeEvent {
+    pub finalize_data: Pubkey,
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+    pub cranker: Pubkey,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ASSET_CLASS_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        nft_manager::NFTManager,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args: CreateAssetClassArgs)]
+pub struct CreateAssetClass<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + AssetClass::INIT_SPACE,
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump
+    )]
+    pub asset_class: Account<'info, AssetClass>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Account<'info, NFTManager>,
+}
+
+impl<'info> CreateAssetClass<'info> {
+    pub fn create_asset_class(
+        &mut self,
+        bumps: &CreateAssetClassBumps,
+        args: CreateAssetClassArgs,
+    ) -> Result<()> {
+        self.asset_class.init(
+            args.metal,
+            args.feed_id,
+            args.price_unit_micrograms,
+            args.enabled,
+            bumps.asset_class,
+        )?;
+
+        emit!(CreateAssetClassEvent {
+            asset_class: self.asset_class.key(),
+            metal: args.metal,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateAssetClassArgs {
+    pub metal: Metal,
+    pub feed_id: [u8; 32],
+    pub price_unit_micrograms: u64,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct CreateAssetClassEvent {
+    pub asset_class: Pubkey,
+    pub metal: Metal,
+}
@@ -4,10 +4,15 @@ use anchor_lang::{
     system_program as system,
 };
 use anchor_spl::{token_2022::Token2022, token_interface};
+use spl_token_group_interface::state::TokenGroup;
 use spl_token_metadata_interface::state::TokenMetadata;
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 
-use crate::constants::{COLLECTION_TAG, COLLECTION_TOKEN_ACCOUNT_TAG};
+use crate::{
+    constants::{COLLECTION_TAG, COLLECTION_TOKEN_ACCOUNT_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::nft_manager::NFTManager,
+};
 
 #[derive(Accounts)]
 
@@ -15,13 +20,22 @@ pub struct CreateCollection<'info> {
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
     pub signer: Signer<'info>,
 
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
     #[account(
         init,
         payer = signer,
-        seeds = [COLLECTION_TAG],
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
         bump,
         mint::decimals = 0,
         mint::authority = mint,
@@ -37,7 +51,7 @@ pub struct CreateCollection<'info> {
     #[account(
         init_if_needed,
         payer = signer,
-        seeds = [COLLECTION_TOKEN_ACCOUNT_TAG],
+        seeds = [COLLECTION_TOKEN_ACCOUNT_TAG, nft_manager.collection_id.as_bytes()],
         bump,
         token::mint = mint,
         token::authority = token_account,
@@ -56,7 +70,11 @@ impl<'info> CreateCollection<'info> {
         let mint = self.mint.to_account_info();
         let token_program = self.token_program.to_account_info();
 
-        let mint_seeds = &[COLLECTION_TAG, &[bumps.mint]];
+        let mint_seeds = &[
+            COLLECTION_TAG,
+            self.nft_manager.collection_id.as_bytes(),
+            &[bumps.mint],
+        ];
         let mint_signer_seeds: &[&[&[u8]]] = &[&mint_seeds[..]];
 
         let token_metadata = TokenMetadata {
@@ -66,7 +84,8 @@ impl<'info> CreateCollection<'info> {
             ..Default::default()
         };
 
-        let data_len = 4 + token_metadata.get_packed_len()?;
+        // Both the metadata and the group are stored as TLV extensions on the mint
+        let data_len = 4 + token_metadata.get_packed_len()? + 4 + std::mem::size_of::<TokenGroup>();
 
         let lamports =
             data_len as u64 * DEFAULT_LAMPORTS_PER_BYTE_YEAR * DEFAULT_EXEMPTION_THRESHOLD as u64;
@@ -99,6 +118,21 @@ impl<'info> CreateCollection<'info> {
             args.uri,
         )?;
 
+        token_interface::token_group_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::TokenGroupInitialize {
+                    token_program_id: token_program.clone(),
+                    group: mint.clone(),
+                    mint: mint.clone(),
+                    mint_authority: mint.clone(),
+                },
+                mint_signer_seeds,
+            ),
+            Some(mint.key()),
+            args.max_size,
+        )?;
+
         token_interface::mint_to(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
@@ -134,4 +168,5 @@ pub struct CreateCollectionArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    pub max_size: u32,
 }
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{ASSET_CLASS_TAG, GRAM_DECIMALS, GRAM_MINT_TAG, GRAM_VAULT_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        gram_vault::GramVault,
+        nft_manager::NFTManager,
+    },
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(args: CreateGramVaultArgs)]
+pub struct CreateGramVault<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + GramVault::INIT_SPACE,
+        seeds = [GRAM_VAULT_TAG, args.metal.seed(), &args.fineness.to_le_bytes()],
+        bump
+    )]
+    pub gram_vault: Box<Account<'info, GramVault>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [GRAM_MINT_TAG, gram_vault.key().as_ref()],
+        bump,
+        mint::decimals = GRAM_DECIMALS,
+        mint::authority = gram_vault,
+        mint::token_program = token_program,
+    )]
+    pub gram_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> CreateGramVault<'info> {
+    pub fn create_gram_vault(
+        &mut self,
+        bumps: &CreateGramVaultBumps,
+        args: CreateGramVaultArgs,
+    ) -> Result<()> {
+        // Validates the fineness
+        utils::Weight::new(1, args.fineness)?;
+
+        self.gram_vault.init(
+            args.metal,
+            args.fineness,
+            self.gram_mint.key(),
+            bumps.gram_vault,
+            bumps.gram_mint,
+        )?;
+
+        emit!(CreateGramVaultEvent {
+            gram_vault: self.gram_vault.key(),
+            gram_mint: self.gram_mint.key(),
+            metal: args.metal,
+            fineness: args.fineness,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateGramVaultArgs {
+    pub metal: Metal,
+    pub fineness: u16,
+}
+
+#[event]
+pub struct CreateGramVaultEvent {
+    pub gram_vault: Pubkey,
+    pub gram_mint: Pubkey,
+    pub metal: Metal,
+    pub fineness: u16,
+}
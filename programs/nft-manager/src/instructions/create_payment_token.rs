@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    constants::{NFT_MANAGER_TAG, PAYMENT_TOKEN_TAG, PAYMENT_VAULT_TAG},
+    errors::NFTManagerError,
+    states::{nft_manager::NFTManager, payment_token::PaymentToken},
+};
+
+#[derive(Accounts)]
+pub struct CreatePaymentToken<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + PaymentToken::INIT_SPACE,
+        seeds = [PAYMENT_TOKEN_TAG, mint.key().as_ref()],
+        bump
+    )]
+    pub payment_token: Box<Account<'info, PaymentToken>>,
+
+    #[account(
+        init,
+        payer = signer,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+        seeds = [PAYMENT_VAULT_TAG, mint.key().as_ref()],
+        bump
+    )]
+    pub vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> CreatePaymentToken<'info> {
+    pub fn create_payment_token(
+        &mut self,
+        bumps: &CreatePaymentTokenBumps,
+        enabled: bool,
+    ) -> Result<()> {
+        self.payment_token.init(
+            self.mint.key(),
+            self.token_program.key(),
+            enabled,
+            bumps.payment_token,
+            bumps.vault,
+        )?;
+
+        emit!(CreatePaymentTokenEvent {
+            payment_token: self.payment_token.key(),
+            mint: self.mint.key(),
+            vault: self.vault.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct CreatePaymentTokenEvent {
+    pub payment_token: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+}
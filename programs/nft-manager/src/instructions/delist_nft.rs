@@ -22,7 +22,7 @@ pub struct DelistNFT<'info> {
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
         bump,
     )]
     pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
@@ -55,8 +55,8 @@ pub struct DelistNFT<'info> {
     pub listing_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
 }
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        COLLECTION_TAG, GRAM_VAULT_TAG, MINT_TAG, NFT_MANAGER_TAG, VAULTED_NFT_TAG,
+        VAULTED_NFT_TOKEN_ACCOUNT_TAG,
+    },
+    errors::NFTManagerError,
+    states::{gram_vault::GramVault, nft_manager::NFTManager, vaulted_nft::VaultedNFT},
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct DepositToGramVault<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+        constraint = mint.supply == 1 @NFTManagerError::InvalidMintSupply,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GRAM_VAULT_TAG, gram_vault.metal.seed(), &gram_vault.fineness.to_le_bytes()],
+        bump = gram_vault.bump,
+    )]
+    pub gram_vault: Box<Account<'info, GramVault>>,
+
+    #[account(
+        mut,
+        address = gram_vault.mint,
+    )]
+    pub gram_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = gram_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub signer_gram_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + VaultedNFT::INIT_SPACE,
+        seeds = [VAULTED_NFT_TAG, mint.key().as_ref()],
+        bump
+    )]
+    pub vaulted_nft: Box<Account<'info, VaultedNFT>>,
+
+    #[account(
+        init,
+        payer = signer,
+        token::mint = mint,
+        token::authority = gram_vault,
+        token::token_program = token_program,
+        seeds = [VAULTED_NFT_TOKEN_ACCOUNT_TAG, mint.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> DepositToGramVault<'info> {
+    pub fn deposit_to_gram_vault(&mut self, bumps: &DepositToGramVaultBumps) -> Result<()> {
+        let signer = self.signer.to_account_info();
+        let mint = self.mint.to_account_info();
+        let token_program = self.token_program.to_account_info();
+
+        let token_metadata = utils::get_metadata(&mint)?;
+
+        utils::validate_collection(
+            &mint,
+            &token_metadata.additional_metadata,
+            self.collection.key(),
+        )?;
+        utils::validate_metal(&token_metadata.additional_metadata, self.gram_vault.metal)?;
+
+        let weight = utils::get_weight(&token_metadata.additional_metadata)?;
+        require_gt!(weight.milligrams, 0, NFTManagerError::InvalidWeight);
+        require_eq!(
+            weight.fineness,
+            self.gram_vault.fineness,
+            NFTManagerError::FinenessMismatch
+        );
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.clone(),
+                token_interface::TransferChecked {
+                    authority: signer.clone(),
+                    from: self.signer_token_account.to_account_info(),
+                    mint: mint.clone(),
+                    to: self.vault_token_account.to_account_info(),
+                },
+            ),
+            1,
+            0,
+        )?;
+
+        let gram_vault_seeds = &[
+            GRAM_VAULT_TAG,
+            self.gram_vault.metal.seed(),
+            &self.gram_vault.fineness.to_le_bytes(),
+            &[self.gram_vault.bump],
+        ];
+        let gram_vault_signer_seeds = &[&gram_vault_seeds[..]];
+
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program,
+                token_interface::MintTo {
+                    mint: self.gram_mint.to_account_info(),
+                    to: self.signer_gram_token_account.to_account_info(),
+                    authority: self.gram_vault.to_account_info(),
+                },
+                gram_vault_signer_seeds,
+            ),
+            weight.milligrams,
+        )?;
+
+        self.vaulted_nft.init(
+            mint.key(),
+            self.gram_vault.key(),
+            weight.milligrams,
+            signer.key(),
+            bumps.vaulted_nft,
+            bumps.vault_token_account,
+        )?;
+
+        self.gram_vault.deposit(weight.milligrams)?;
+
+        emit!(DepositToGramVaultEvent {
+            gram_vault: self.gram_vault.key(),
+            mint: mint.key(),
+            depositor: signer.key(),
+            weight: weight.milligrams,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DepositToGramVaultEvent {
+    pub gram_vault: Pubkey,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub weight: u64,
+}
@@ -2,9 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
 
 use crate::{
-    constants::{COLLECTION_TAG, FINALIZE_FRACTIONALIZE_DATA_TAG, MINT_TAG, NFT_MANAGER_TAG},
+    constants::{
+        COLLECTION_TAG, FINALIZE_FRACTIONALIZE_DATA_TAG, FINALIZE_INDEX_TAG, LINEAGE_TAG, MINT_TAG,
+        NFT_MANAGER_TAG, RESERVE_LEDGER_TAG,
+    },
     errors::NFTManagerError,
-    states::{finalize_fractionalize_data::FinalizeFractionalizeData, nft_manager::NFTManager},
+    states::{
+        finalize_fractionalize_data::FinalizeFractionalizeData, finalize_index::FinalizeIndex,
+        lineage::Lineage, nft_manager::NFTManager, reserve_ledger::ReserveLedger,
+    },
     utils,
 };
 
@@ -15,12 +21,15 @@ pub struct FinalizeFractionalizeNFT<'info> {
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = finalize_data.payer @NFTManagerError::NotOwner
+    )]
     pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
         bump,
         address = finalize_data.mint @NFTManagerError::MintFinalizeDataMismatch
     )]
@@ -30,7 +39,7 @@ pub struct FinalizeFractionalizeNFT<'info> {
     #[account(
         init,
         payer = signer,
-        seeds = [MINT_TAG, &nft_manager.discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &nft_manager.discriminant.to_le_bytes()],
         bump,
         mint::decimals = 0,
         mint::authority = new_mint,
@@ -53,7 +62,8 @@ pub struct FinalizeFractionalizeNFT<'info> {
     pub new_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(
-        seeds = [COLLECTION_TAG],
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     /// CHECK: This account is validated
@@ -61,7 +71,6 @@ pub struct FinalizeFractionalizeNFT<'info> {
 
     #[account(
         mut,
-        close = signer,
         seeds = [FINALIZE_FRACTIONALIZE_DATA_TAG, mint.key().as_ref()],
         bump,
     )]
@@ -69,24 +78,52 @@ pub struct FinalizeFractionalizeNFT<'info> {
 
     #[account(
         mut,
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        mut,
+        seeds = [LINEAGE_TAG, finalize_data.provenance.root.as_ref()],
+        bump = lineage.bump,
+    )]
+    pub lineage: Box<Account<'info, Lineage>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
 }
 
 impl<'info> FinalizeFractionalizeNFT<'info> {
     pub fn finalize_mint_nft(&mut self, bumps: &FinalizeFractionalizeNFTBumps) -> Result<()> {
+        let part = self
+            .finalize_data
+            .next_part()
+            .ok_or(NFTManagerError::InvalidFinalizeData)?;
+
         let collection_key = self.collection.key();
         let new_mint_discriminant = self.nft_manager.discriminant;
-        let weight = self.finalize_data.weight;
+        let weight = utils::Weight::new(part.weight, self.finalize_data.fineness)?;
         let new_mint = self.new_mint.to_account_info();
         let system_program = self.system_program.to_account_info();
         let token_program = self.token_program.to_account_info();
         let payer = self.signer.to_account_info();
 
+        let collection_id = self.nft_manager.collection_id.clone();
         let new_mint_seeds = &[
             MINT_TAG,
+            collection_id.as_bytes(),
             &new_mint_discriminant.to_le_bytes(),
             &[bumps.new_mint],
         ];
@@ -94,15 +131,18 @@ impl<'info> FinalizeFractionalizeNFT<'info> {
         let new_mint_signer_seeds = &[&new_mint_seeds[..]];
 
         utils::mint_nft(
-            self.finalize_data.name.clone(),
-            self.finalize_data.symbol.clone(),
-            self.finalize_data.uri.clone(),
-            weight,
+            part.name,
+            part.symbol,
+            part.uri,
+            weight.milligrams,
+            self.finalize_data.metal,
             new_mint_discriminant,
             new_mint_signer_seeds,
             &mut self.nft_manager,
             self.new_mint.to_account_info(),
             self.new_token_account.to_account_info(),
+            self.collection.to_account_info(),
+            bumps.collection,
             token_program.clone(),
             self.system_program.to_account_info(),
             self.signer.to_account_info(),
@@ -114,13 +154,47 @@ impl<'info> FinalizeFractionalizeNFT<'info> {
             token_program,
             payer,
             collection_key,
-            weight,
+            &weight,
+            new_mint_signer_seeds,
+        )?;
+
+        let provenance = self.finalize_data.provenance;
+        let mut fields = utils::provenance_metadata(&provenance);
+        fields.extend(utils::bar_serial_metadata(
+            self.finalize_data.bar_serial.as_deref(),
+        ));
+        utils::update_additional_metadata(
+            self.new_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            fields,
             new_mint_signer_seeds,
         )?;
 
+        self.lineage
+            .record(self.new_mint.key(), &provenance, weight.milligrams)?;
+        utils::realloc_account(
+            self.lineage.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + Lineage::calculate_space(self.lineage.entries.len()),
+        )?;
+
+        self.reserve_ledger
+            .record_split(self.nft_manager.collection, self.finalize_data.metal)?;
+
+        let remaining_parts = self.finalize_data.parts.len() as u8;
+
+        if remaining_parts == 0 {
+            self.finalize_index.remove(&self.finalize_data.key());
+            self.finalize_data.close(self.signer.to_account_info())?;
+        }
+
         emit!(FinalizeFractionalizeNFTEvent {
             mint: self.mint.key(),
-            new_mint: self.new_mint.key()
+            new_mint: self.new_mint.key(),
+            remaining_parts,
         });
 
         Ok(())
@@ -131,4 +205,5 @@ impl<'info> FinalizeFractionalizeNFT<'info> {
 pub struct FinalizeFractionalizeNFTEvent {
     pub mint: Pubkey,
     pub new_mint: Pubkey,
+    pub remaining_parts: u8,
 }
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022};
+
+use crate::{
+    constants::{
+        COLLECTION_TAG, FINALIZE_FRACTIONALIZE_DATA_TAG, FINALIZE_INDEX_TAG, LINEAGE_TAG, MINT_TAG,
+        NFT_MANAGER_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        finalize_fractionalize_data::FinalizeFractionalizeData, finalize_index::FinalizeIndex,
+        lineage::Lineage, nft_manager::NFTManager, reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+use super::FinalizeFractionalizeNFTEvent;
+
+/// Remaining accounts are passed as `[new_mint, new_token_account]` pairs, one per part to
+/// finalize, with mints derived from consecutive discriminants.
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct FinalizeFractionalizeNFTBatch<'info> {
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        mut,
+        address = finalize_data.payer @NFTManagerError::NotOwner
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+        address = finalize_data.mint @NFTManagerError::MintFinalizeDataMismatch
+    )]
+    /// CHECK: The mint account to finalize
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_FRACTIONALIZE_DATA_TAG, mint.key().as_ref()],
+        bump,
+    )]
+    pub finalize_data: Box<Account<'info, FinalizeFractionalizeData>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        mut,
+        seeds = [LINEAGE_TAG, finalize_data.provenance.root.as_ref()],
+        bump = lineage.bump,
+    )]
+    pub lineage: Box<Account<'info, Lineage>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+}
+
+impl<'info> FinalizeFractionalizeNFTBatch<'info> {
+    pub fn finalize_fractionalize_nft_batch(
+        &mut self,
+        bumps: &FinalizeFractionalizeNFTBatchBumps,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let signer = self.signer.to_account_info();
+        let system_program = self.system_program.to_account_info();
+        let token_program = self.token_program.to_account_info();
+        let count = remaining_accounts.len() / 2;
+
+        require!(
+            count > 0 && count <= self.finalize_data.parts.len(),
+            NFTManagerError::InvalidBatchSize
+        );
+        require_eq!(
+            remaining_accounts.len(),
+            count * 2,
+            NFTManagerError::InvalidBatchSize
+        );
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        for accounts in remaining_accounts.chunks_exact(2) {
+            let new_mint = &accounts[0];
+            let new_token_account = &accounts[1];
+            let part = self
+                .finalize_data
+                .next_part()
+                .ok_or(NFTManagerError::InvalidFinalizeData)?;
+            let weight = utils::Weight::new(part.weight, self.finalize_data.fineness)?;
+            let discriminant = self.nft_manager.discriminant;
+
+            let (new_mint_key, new_mint_bump) = Pubkey::find_program_address(
+                &[
+                    MINT_TAG,
+                    collection_id.as_bytes(),
+                    &discriminant.to_le_bytes(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(new_mint.key(), new_mint_key, NFTManagerError::InvalidMint);
+
+            let new_mint_seeds = &[
+                MINT_TAG,
+                collection_id.as_bytes(),
+                &discriminant.to_le_bytes()[..],
+                &[new_mint_bump],
+            ];
+            let new_mint_signer_seeds = &[&new_mint_seeds[..]];
+
+            utils::create_nft_mint(
+                new_mint.clone(),
+                token_program.clone(),
+                system_program.clone(),
+                signer.clone(),
+                new_mint_signer_seeds,
+            )?;
+
+            anchor_spl::associated_token::create_idempotent(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                anchor_spl::associated_token::Create {
+                    payer: signer.clone(),
+                    associated_token: new_token_account.clone(),
+                    authority: signer.clone(),
+                    mint: new_mint.clone(),
+                    system_program: system_program.clone(),
+                    token_program: token_program.clone(),
+                },
+            ))?;
+
+            utils::mint_nft_with_metadata(
+                part.name,
+                part.symbol,
+                part.uri,
+                &weight,
+                self.finalize_data.metal,
+                discriminant,
+                new_mint_signer_seeds,
+                &mut self.nft_manager,
+                new_mint.clone(),
+                new_token_account.clone(),
+                self.collection.to_account_info(),
+                bumps.collection,
+                token_program.clone(),
+                system_program.clone(),
+                signer.clone(),
+            )?;
+
+            let provenance = self.finalize_data.provenance;
+            let mut fields = utils::provenance_metadata(&provenance);
+            fields.extend(utils::bar_serial_metadata(
+                self.finalize_data.bar_serial.as_deref(),
+            ));
+            utils::update_additional_metadata(
+                new_mint.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                signer.clone(),
+                fields,
+                new_mint_signer_seeds,
+            )?;
+            self.lineage
+                .record(new_mint_key, &provenance, weight.milligrams)?;
+            self.reserve_ledger
+                .record_split(self.nft_manager.collection, self.finalize_data.metal)?;
+
+            emit!(FinalizeFractionalizeNFTEvent {
+                mint: self.mint.key(),
+                new_mint: new_mint_key,
+                remaining_parts: self.finalize_data.parts.len() as u8,
+            });
+        }
+
+        utils::realloc_account(
+            self.lineage.to_account_info(),
+            signer.clone(),
+            system_program,
+            8 + Lineage::calculate_space(self.lineage.entries.len()),
+        )?;
+
+        if self.finalize_data.parts.is_empty() {
+            self.finalize_index.remove(&self.finalize_data.key());
+            self.finalize_data.close(signer)?;
+        }
+
+        Ok(())
+    }
+}
@@ -2,9 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token_2022::Token2022, token_interface};
 
 use crate::{
-    constants::{COLLECTION_TAG, FINALIZE_MINT_DATA_TAG, MINT_TAG, NFT_MANAGER_TAG},
+    constants::{
+        COLLECTION_TAG, FINALIZE_INDEX_TAG, FINALIZE_MINT_DATA_TAG, MINT_TAG, NFT_MANAGER_TAG,
+    },
     errors::NFTManagerError,
-    states::{finalize_mint_data::FinalizeMintData, nft_manager::NFTManager},
+    states::{
+        finalize_index::FinalizeIndex, finalize_mint_data::FinalizeMintData,
+        nft_manager::NFTManager,
+    },
     utils,
 };
 
@@ -14,18 +19,21 @@ pub struct FinalizeMintNFT<'info> {
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = finalize_data.payer @NFTManagerError::NotOwner
+    )]
     pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
         bump,
     )]
     pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
-        seeds = [COLLECTION_TAG],
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     /// CHECK: This account is validated
@@ -42,10 +50,17 @@ pub struct FinalizeMintNFT<'info> {
 
     #[account(
         mut,
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
 }
 
 impl<'info> FinalizeMintNFT<'info> {
@@ -55,13 +70,19 @@ impl<'info> FinalizeMintNFT<'info> {
         discriminant: u64,
     ) -> Result<()> {
         let collection_key = self.collection.key();
-        let weight = self.finalize_data.weight;
+        let weight = utils::Weight::new(self.finalize_data.weight, self.finalize_data.fineness)?;
         let mint = self.mint.to_account_info();
         let system_program = self.system_program.to_account_info();
         let token_program = self.token_program.to_account_info();
         let payer = self.signer.to_account_info();
 
-        let mint_seeds = &[MINT_TAG, &discriminant.to_le_bytes()[..], &[bumps.mint]];
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes()[..],
+            &[bumps.mint],
+        ];
         let mint_signer_seeds = &[&mint_seeds[..]];
 
         utils::update_metadata_standard(
@@ -70,13 +91,16 @@ impl<'info> FinalizeMintNFT<'info> {
             token_program,
             payer,
             collection_key,
-            weight,
+            &weight,
             mint_signer_seeds,
         )?;
 
+        self.finalize_index.remove(&self.finalize_data.key());
+
         emit!(FinalizeMintNFTEvent {
             mint: self.mint.key(),
-            weight,
+            weight: weight.milligrams,
+            fineness: weight.fineness,
         });
 
         Ok(())
@@ -87,4 +111,5 @@ impl<'info> FinalizeMintNFT<'info> {
 pub struct FinalizeMintNFTEvent {
     pub mint: Pubkey,
     pub weight: u64,
+    pub fineness: u16,
 }
@@ -11,8 +11,8 @@ pub struct FinalizeOwnershipTransfer<'info> {
 
     #[account(
         mut,
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Account<'info, NFTManager>,
 }
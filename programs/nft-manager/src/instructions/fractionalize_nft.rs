@@ -1,36 +1,47 @@
 use anchor_lang::{prelude::*, solana_program::program::invoke_signed, system_program as system};
 use anchor_spl::{token_2022::Token2022, token_interface};
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
     constants::{
-        COLLECTION_KEY, COLLECTION_TAG, FEES_COLLECTOR_TAG, FINALIZE_FRACTIONALIZE_DATA_TAG,
-        MINT_TAG, NFT_MANAGER_TAG, WEIGHT_KEY,
+        ASSET_CLASS_TAG, COLLECTION_KEY, COLLECTION_TAG, FEES_COLLECTOR_TAG,
+        FINALIZE_FRACTIONALIZE_DATA_TAG, FINALIZE_INDEX_TAG, FINENESS_KEY, LINEAGE_TAG,
+        MAX_FRACTIONALIZE_PARTS, MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG, WEIGHT_KEY,
     },
     errors::NFTManagerError,
     states::{
-        fees_collector::FeesCollector, finalize_fractionalize_data::FinalizeFractionalizeData,
+        asset_class::AssetClass,
+        fees_collector::FeesCollector,
+        finalize_fractionalize_data::{FinalizeFractionalizeData, FractionalizePart},
+        finalize_index::{FinalizeIndex, FinalizeKind, PendingFinalization},
+        lineage::Lineage,
         nft_manager::NFTManager,
+        oracle_config::OracleConfig,
     },
     utils,
 };
 
-use super::MintNFTArgs;
+use super::NFTPartArgs;
 
 #[derive(Accounts)]
 #[instruction(args: FractionalizeNFTArgs)]
 pub struct FractionalizeNFT<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token2022>,
-    pub gold_price_update: Box<Account<'info, PriceUpdateV2>>,
-    pub sol_price_update: Box<Account<'info, PriceUpdateV2>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &args.discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &args.discriminant.to_le_bytes()],
         bump,
     )]
     pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
@@ -44,7 +55,7 @@ pub struct FractionalizeNFT<'info> {
     pub token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(
-        seeds = [COLLECTION_TAG],
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     /// CHECK: This account is validated
@@ -53,23 +64,54 @@ pub struct FractionalizeNFT<'info> {
     #[account(
         init,
         payer = signer,
-        space = 8 + FinalizeFractionalizeData::calculate_space(args.part_b),
+        space = 8 + FinalizeFractionalizeData::calculate_space(args.parts.get(1..).unwrap_or_default()),
         seeds = [FINALIZE_FRACTIONALIZE_DATA_TAG, mint.key().as_ref(),],
         bump,
     )]
     pub finalize_data: Box<Account<'info, FinalizeFractionalizeData>>,
 
+    /// CHECK: Original bar the mint was cut from, or the mint itself, checked against its metadata
+    pub root_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + Lineage::calculate_space(1),
+        seeds = [LINEAGE_TAG, root_mint.key().as_ref()],
+        bump,
+    )]
+    pub lineage: Box<Account<'info, Lineage>>,
+
     #[account(
         mut,
-        seeds = [FEES_COLLECTOR_TAG],
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     pub fees_collector: Box<Account<'info, FeesCollector>>,
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, asset_class.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
 }
 
 impl<'info> FractionalizeNFT<'info> {
@@ -83,25 +125,64 @@ impl<'info> FractionalizeNFT<'info> {
 
         let FractionalizeNFTArgs {
             discriminant,
-            part_b,
-            part_a,
+            mut parts,
+            max_lamports,
         } = args;
 
+        require!(
+            parts.len() >= 2 && parts.len() <= MAX_FRACTIONALIZE_PARTS as usize,
+            NFTManagerError::InvalidPartsCount
+        );
+
         let token_metadata = utils::get_metadata(mint)?;
 
-        let weight = utils::get_weight(&token_metadata.additional_metadata)?;
-        utils::validate_fractions(weight, part_a.weight, part_b.weight)?;
+        let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+        let part_weights: Vec<u64> = parts.iter().map(|part| part.weight).collect();
+        utils::validate_fractions(weight.milligrams, &part_weights)?;
+        for part_weight in part_weights {
+            self.asset_class.validate_weight(part_weight)?;
+        }
+        require!(
+            parts.iter().all(|part| part.fineness == weight.fineness),
+            NFTManagerError::FinenessMismatch
+        );
+
+        let metal = self.asset_class.metal;
+        utils::validate_metal(&token_metadata.additional_metadata, metal)?;
+        require!(
+            parts.iter().all(|part| part.metal == metal),
+            NFTManagerError::MetalMismatch
+        );
 
-        let value_in_lamports = utils::get_gold_value_in_lamport(
-            &self.gold_price_update,
-            &self.sol_price_update,
-            weight,
+        let provenance = utils::get_provenance(mint.key(), &token_metadata.additional_metadata)?;
+        require_keys_eq!(
+            provenance.root,
+            self.root_mint.key(),
+            NFTManagerError::InvalidLineage
+        );
+
+        // The first part keeps the original mint, the others are minted on finalize
+        let part_a = parts.remove(0);
+
+        let value_in_lamports = utils::get_metal_value_in_lamport(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
         )?;
 
-        let fees = value_in_lamports
-            .checked_mul(self.fees_collector.fractionalize_fee as u64)
-            .and_then(|v| v.checked_div(10u64.pow(self.fees_collector.fees_decimals as u32)))
-            .ok_or(NFTManagerError::Overflow)?;
+        let fees = self
+            .fees_collector
+            .calc_fee(value_in_lamports, self.fees_collector.fractionalize_fee)?;
+
+        require_gte!(max_lamports, fees, NFTManagerError::SlippageExceeded);
 
         system::transfer(
             CpiContext::new(
@@ -114,7 +195,13 @@ impl<'info> FractionalizeNFT<'info> {
             fees,
         )?;
 
-        let mint_seeds = &[MINT_TAG, &discriminant.to_le_bytes(), &[bumps.mint]];
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes(),
+            &[bumps.mint],
+        ];
         let mint_signer_seeds = &[&mint_seeds[..]];
 
         invoke_signed(
@@ -129,6 +216,18 @@ impl<'info> FractionalizeNFT<'info> {
             mint_signer_seeds,
         )?;
 
+        invoke_signed(
+            &token_interface::spl_token_metadata_interface::instruction::remove_key(
+                &token_program.key(),     // token program id
+                &mint.key(),              // "metadata" account
+                &mint.key(),              // update authority
+                FINENESS_KEY.to_string(), // key to remove
+                true, // idempotent flag, if true transaction will not fail if key does not exist
+            ),
+            &[token_program.clone(), mint.clone(), mint.clone()],
+            mint_signer_seeds,
+        )?;
+
         invoke_signed(
             &token_interface::spl_token_metadata_interface::instruction::remove_key(
                 &token_program.key(),       // token program id
@@ -145,21 +244,62 @@ impl<'info> FractionalizeNFT<'info> {
             token_program.clone(),
             mint.clone(),
             mint_signer_seeds,
-            part_a.weight,
+            &utils::Weight::new(part_a.weight, part_a.fineness)?,
             &part_a.name,
             &part_a.symbol,
             &part_a.uri,
             &self.collection.key().to_string(),
         )?;
 
-        self.finalize_data
-            .as_mut()
-            .init(mint.key(), part_b, bumps.finalize_data)?;
+        if self.lineage.root == Pubkey::default() {
+            self.lineage.init(provenance.root, bumps.lineage)?;
+        }
+        self.lineage
+            .record(mint.key(), &provenance, part_a.weight)?;
+        utils::realloc_account(
+            self.lineage.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + Lineage::calculate_space(self.lineage.entries.len()),
+        )?;
+
+        self.finalize_data.as_mut().init(
+            mint.key(),
+            weight.fineness,
+            metal,
+            parts,
+            provenance.child(mint.key())?,
+            utils::get_bar_serial(&token_metadata.additional_metadata).map(str::to_string),
+            fees,
+            FractionalizePart {
+                weight: weight.milligrams,
+                name: token_metadata.name,
+                symbol: token_metadata.symbol,
+                uri: token_metadata.uri,
+            },
+            self.signer.key(),
+            bumps.finalize_data,
+        )?;
+        utils::realloc_account(
+            self.finalize_data.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + self.finalize_data.space(),
+        )?;
+
+        self.finalize_index.add(PendingFinalization {
+            finalize_data: self.finalize_data.key(),
+            mint: mint.key(),
+            kind: FinalizeKind::Fractionalize,
+            created_at: self.finalize_data.created_at,
+        })?;
 
         emit!(FractionalizeNFTEvent {
             mint: mint.key(),
             finalize_data: self.finalize_data.key(),
             discriminant,
+            quoted: max_lamports,
+            charged: fees,
         });
 
         Ok(())
@@ -169,8 +309,9 @@ impl<'info> FractionalizeNFT<'info> {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct FractionalizeNFTArgs {
     pub discriminant: u64,
-    pub part_b: MintNFTArgs,
-    pub part_a: MintNFTArgs,
+    /// Parts of the NFT, the first one is kept by the original mint
+    pub parts: Vec<NFTPartArgs>,
+    pub max_lamports: u64,
 }
 
 #[event]
@@ -178,4 +319,8 @@ pub struct FractionalizeNFTEvent {
     pub mint: Pubkey,
     pub finalize_data: Pubkey,
     pub discriminant: u64,
+    /// Most lamports the signer accepted to pay
+    pub quoted: u64,
+    /// Fractionalize fee charged
+    pub charged: u64,
 }
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        BAR_RECORD_TAG, BAR_REGISTRY_TAG, MINT_TAG, NFT_MANAGER_TAG, REDEMPTION_REQUEST_TAG,
+        REDEMPTION_TOKEN_ACCOUNT_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        asset_class::Metal,
+        bar_record::BarRecord,
+        bar_registry::BarRegistry,
+        nft_manager::NFTManager,
+        redemption_request::{RedemptionRequest, RedemptionStatus},
+        reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct FulfillRedemption<'info> {
+    pub token_program: Program<'info, Token2022>,
+
+    #[account(address = bar_registry.operator @NFTManagerError::OnlyOperatorAllowed)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = redemption_request.owner @NFTManagerError::InvalidRedemptionOwner,
+    )]
+    /// CHECK: Receives the rent of the closed accounts
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        seeds = [REDEMPTION_REQUEST_TAG, mint.key().as_ref()],
+        bump = redemption_request.bump,
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequest>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = redemption_token_account,
+        seeds = [REDEMPTION_TOKEN_ACCOUNT_TAG, redemption_request.key().as_ref()],
+        bump
+    )]
+    pub redemption_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [BAR_REGISTRY_TAG],
+        bump = bar_registry.bump,
+    )]
+    pub bar_registry: Box<Account<'info, BarRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    /// Required when the NFT carries a bar serial
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, bar_record.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Option<Box<Account<'info, BarRecord>>>,
+}
+
+impl<'info> FulfillRedemption<'info> {
+    /// Burns the escrowed NFT once the metal was handed over and closes its mint.
+    pub fn fulfill_redemption(
+        &mut self,
+        bumps: &FulfillRedemptionBumps,
+        discriminant: u64,
+    ) -> Result<()> {
+        let token_program = self.token_program.to_account_info();
+        let mint = self.mint.to_account_info();
+        let owner = self.owner.to_account_info();
+        let redemption_token_account = self.redemption_token_account.to_account_info();
+        let redemption_request_key = self.redemption_request.key();
+
+        self.redemption_request
+            .resolve(RedemptionStatus::Fulfilled)?;
+
+        let token_metadata = utils::get_metadata(&mint)?;
+        let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+
+        if let Some(serial) = utils::get_bar_serial(&token_metadata.additional_metadata) {
+            let bar_record = self
+                .bar_record
+                .as_mut()
+                .ok_or(NFTManagerError::BarMismatch)?;
+            require!(bar_record.serial == serial, NFTManagerError::BarMismatch);
+
+            bar_record.redeem(&weight)?;
+        }
+
+        self.reserve_ledger.record_burn(
+            self.nft_manager.collection,
+            self.redemption_request.metal,
+            weight.milligrams,
+        )?;
+
+        let redemption_token_account_seeds = &[
+            REDEMPTION_TOKEN_ACCOUNT_TAG,
+            redemption_request_key.as_ref(),
+            &[bumps.redemption_token_account],
+        ];
+        let redemption_token_account_signer_seeds = &[&redemption_token_account_seeds[..]];
+
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::Burn {
+                    mint: mint.clone(),
+                    from: redemption_token_account.clone(),
+                    authority: redemption_token_account.clone(),
+                },
+                redemption_token_account_signer_seeds,
+            ),
+            1,
+        )?;
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::CloseAccount {
+                account: redemption_token_account.clone(),
+                destination: owner.clone(),
+                authority: redemption_token_account,
+            },
+            redemption_token_account_signer_seeds,
+        ))?;
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes(),
+            &[bumps.mint],
+        ];
+        let mint_signer_seeds = &[&mint_seeds[..]];
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program,
+            token_interface::CloseAccount {
+                account: mint.clone(),
+                destination: owner.clone(),
+                authority: mint.clone(),
+            },
+            mint_signer_seeds,
+        ))?;
+
+        emit!(FulfillRedemptionEvent {
+            redemption_request: redemption_request_key,
+            owner: owner.key(),
+            mint: mint.key(),
+            metal: self.redemption_request.metal,
+            weight: weight.milligrams,
+            fineness: weight.fineness,
+            delivery_hash: self.redemption_request.delivery_hash,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct FulfillRedemptionEvent {
+    pub redemption_request: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub metal: Metal,
+    pub weight: u64,
+    pub fineness: u16,
+    pub delivery_hash: [u8; 32],
+}
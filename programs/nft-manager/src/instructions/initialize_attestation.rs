@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ATTESTATION_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{asset_class::Metal, attestation::Attestation, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+#[instruction(args: InitializeAttestationArgs)]
+pub struct InitializeAttestation<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + Attestation::INIT_SPACE,
+        seeds = [ATTESTATION_TAG, args.metal.seed()],
+        bump
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> InitializeAttestation<'info> {
+    pub fn initialize_attestation(
+        &mut self,
+        bumps: &InitializeAttestationBumps,
+        args: InitializeAttestationArgs,
+    ) -> Result<()> {
+        self.attestation
+            .init(args.metal, args.auditor, args.max_age, bumps.attestation)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitializeAttestationArgs {
+    pub metal: Metal,
+    pub auditor: Pubkey,
+    /// Seconds a report can be relied on for minting
+    pub max_age: u64,
+}
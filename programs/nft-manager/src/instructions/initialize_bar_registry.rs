@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BAR_REGISTRY_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{bar_registry::BarRegistry, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+pub struct InitializeBarRegistry<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + BarRegistry::INIT_SPACE,
+        seeds = [BAR_REGISTRY_TAG],
+        bump
+    )]
+    pub bar_registry: Box<Account<'info, BarRegistry>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> InitializeBarRegistry<'info> {
+    pub fn initialize_bar_registry(
+        &mut self,
+        bumps: &InitializeBarRegistryBumps,
+        operator: Pubkey,
+    ) -> Result<()> {
+        self.bar_registry.init(operator, bumps.bar_registry)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{COLLECTION_TAG, FEES_COLLECTOR_TAG, MINT_FEES_COLLECTOR_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{
+        fees_collector::FeesCollector, mint_fees_collector::MintFeesCollector,
+        nft_manager::NFTManager,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args: InitializeCollectionManagerArgs)]
+pub struct InitializeCollectionManager<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = root_nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = root_nft_manager.bump,
+    )]
+    pub root_nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [COLLECTION_TAG, args.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated via the seeds
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FeesCollector::INIT_SPACE,
+        seeds = [FEES_COLLECTOR_TAG, args.collection_id.as_bytes()],
+        bump
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + MintFeesCollector::INIT_SPACE,
+        seeds = [MINT_FEES_COLLECTOR_TAG, args.collection_id.as_bytes()],
+        bump
+    )]
+    pub mint_fees_collector: Box<Account<'info, MintFeesCollector>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + NFTManager::INIT_SPACE,
+        seeds = [NFT_MANAGER_TAG, args.collection_id.as_bytes()],
+        bump
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> InitializeCollectionManager<'info> {
+    pub fn initialize_collection_manager(
+        &mut self,
+        bumps: &InitializeCollectionManagerBumps,
+        args: InitializeCollectionManagerArgs,
+    ) -> Result<()> {
+        // The empty id belongs to the default collection
+        require!(
+            !args.collection_id.is_empty(),
+            NFTManagerError::InvalidCollectionId
+        );
+
+        self.nft_manager.init(
+            args.authority,
+            bumps.nft_manager,
+            self.collection.key(),
+            args.collection_id,
+        )?;
+
+        self.fees_collector.init(
+            args.fractionalize_fee,
+            args.sell_fee,
+            args.mint_premium_bps,
+            args.fabrication_fee,
+            args.merge_fee,
+            args.cancel_refund_bps,
+            bumps.fees_collector,
+        )?;
+
+        self.mint_fees_collector.init(bumps.mint_fees_collector)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitializeCollectionManagerArgs {
+    pub collection_id: String,
+    pub authority: Pubkey,
+    pub fractionalize_fee: u32,
+    pub sell_fee: u32,
+    pub mint_premium_bps: u16,
+    pub fabrication_fee: u64,
+    pub merge_fee: u64,
+    pub cancel_refund_bps: u16,
+}
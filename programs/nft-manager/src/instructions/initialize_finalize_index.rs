@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FINALIZE_INDEX_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{finalize_index::FinalizeIndex, nft_manager::NFTManager},
+};
+
+/// Creates the finalize index of a deployment initialized before it existed.
+#[derive(Accounts)]
+pub struct InitializeFinalizeIndex<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FinalizeIndex::INIT_SPACE,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> InitializeFinalizeIndex<'info> {
+    pub fn initialize_finalize_index(
+        &mut self,
+        bumps: &InitializeFinalizeIndexBumps,
+    ) -> Result<()> {
+        self.finalize_index.init(bumps.finalize_index)?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{COLLECTION_TAG, FEES_COLLECTOR_TAG, MINT_FEES_COLLECTOR_TAG, NFT_MANAGER_TAG},
+    constants::{
+        COLLECTION_TAG, FEES_COLLECTOR_TAG, FINALIZE_INDEX_TAG, MINT_FEES_COLLECTOR_TAG,
+        NFT_MANAGER_TAG, ORACLE_CONFIG_TAG,
+    },
     states::{
-        fees_collector::FeesCollector, mint_fees_collector::MintFeesCollector,
-        nft_manager::NFTManager,
+        fees_collector::FeesCollector, finalize_index::FinalizeIndex,
+        mint_fees_collector::MintFeesCollector, nft_manager::NFTManager,
+        oracle_config::OracleConfig,
     },
 };
 
@@ -50,6 +54,24 @@ pub struct InitializeNFTManager<'info> {
         bump
     )]
     pub nft_manager: Account<'info, NFTManager>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + OracleConfig::INIT_SPACE,
+        seeds = [ORACLE_CONFIG_TAG],
+        bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FinalizeIndex::INIT_SPACE,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
 }
 
 impl<'info> InitializeNFTManager<'info> {
@@ -58,14 +80,36 @@ impl<'info> InitializeNFTManager<'info> {
         bumps: &InitializeNFTManagerBumps,
         args: InitializeNFTManagerArgs,
     ) -> Result<()> {
-        self.nft_manager
-            .init(self.signer.key(), bumps.nft_manager, self.collection.key())?;
+        self.nft_manager.init(
+            self.signer.key(),
+            bumps.nft_manager,
+            self.collection.key(),
+            String::new(),
+        )?;
 
-        self.fees_collector
-            .init(args.fractionalize_fee, args.sell_fee, bumps.fees_collector)?;
+        self.fees_collector.init(
+            args.fractionalize_fee,
+            args.sell_fee,
+            args.mint_premium_bps,
+            args.fabrication_fee,
+            args.merge_fee,
+            args.cancel_refund_bps,
+            bumps.fees_collector,
+        )?;
 
         self.mint_fees_collector.init(bumps.mint_fees_collector)?;
 
+        self.oracle_config.init(
+            args.sol_feed_id,
+            args.max_age,
+            args.max_conf_bps,
+            args.price_update_owners,
+            args.max_deviation_bps,
+            bumps.oracle_config,
+        )?;
+
+        self.finalize_index.init(bumps.finalize_index)?;
+
         Ok(())
     }
 }
@@ -74,4 +118,13 @@ impl<'info> InitializeNFTManager<'info> {
 pub struct InitializeNFTManagerArgs {
     pub fractionalize_fee: u32,
     pub sell_fee: u32,
+    pub mint_premium_bps: u16,
+    pub fabrication_fee: u64,
+    pub merge_fee: u64,
+    pub cancel_refund_bps: u16,
+    pub sol_feed_id: [u8; 32],
+    pub max_age: u64,
+    pub max_conf_bps: u16,
+    pub price_update_owners: Vec<Pubkey>,
+    pub max_deviation_bps: u16,
 }
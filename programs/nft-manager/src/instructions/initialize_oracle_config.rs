@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{NFT_MANAGER_TAG, ORACLE_CONFIG_TAG},
+    errors::NFTManagerError,
+    states::{nft_manager::NFTManager, oracle_config::OracleConfig},
+};
+
+/// Creates the oracle config of a deployment initialized before it existed.
+#[derive(Accounts)]
+pub struct InitializeOracleConfig<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + OracleConfig::INIT_SPACE,
+        seeds = [ORACLE_CONFIG_TAG],
+        bump
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> InitializeOracleConfig<'info> {
+    pub fn initialize_oracle_config(
+        &mut self,
+        bumps: &InitializeOracleConfigBumps,
+        args: InitializeOracleConfigArgs,
+    ) -> Result<()> {
+        self.oracle_config.init(
+            args.sol_feed_id,
+            args.max_age,
+            args.max_conf_bps,
+            args.price_update_owners,
+            args.max_deviation_bps,
+            bumps.oracle_config,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitializeOracleConfigArgs {
+    pub sol_feed_id: [u8; 32],
+    pub max_age: u64,
+    pub max_conf_bps: u16,
+    pub price_update_owners: Vec<Pubkey>,
+    pub max_deviation_bps: u16,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{NFT_MANAGER_TAG, RESERVE_LEDGER_TAG},
+    errors::NFTManagerError,
+    states::{
+        nft_manager::NFTManager,
+        reserve_ledger::{CollectionReserve, ReserveLedger},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args: InitializeReserveLedgerArgs)]
+pub struct InitializeReserveLedger<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ReserveLedger::calculate_space(args.opening.len()),
+        seeds = [RESERVE_LEDGER_TAG],
+        bump
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> InitializeReserveLedger<'info> {
+    pub fn initialize_reserve_ledger(
+        &mut self,
+        bumps: &InitializeReserveLedgerBumps,
+        args: InitializeReserveLedgerArgs,
+    ) -> Result<()> {
+        self.reserve_ledger
+            .init(bumps.reserve_ledger, args.opening)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitializeReserveLedgerArgs {
+    /// Empty on a fresh deployment
+    pub opening: Vec<CollectionReserve>,
+}
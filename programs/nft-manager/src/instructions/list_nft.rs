@@ -20,7 +20,7 @@ pub struct ListNFT<'info> {
     pub owner: Signer<'info>,
 
     #[account(
-        seeds = [MINT_TAG, &args.discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &args.discriminant.to_le_bytes()],
         bump,
         constraint = mint.supply == 1 @NFTManagerError::InvalidMintSupply,
     )]
@@ -35,7 +35,7 @@ pub struct ListNFT<'info> {
     pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(
-        seeds = [COLLECTION_TAG],
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     /// CHECK: This account is validated
@@ -61,8 +61,8 @@ pub struct ListNFT<'info> {
     pub listing_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
 }
@@ -75,7 +75,11 @@ impl<'info> ListNFT<'info> {
 
         let token_metadata = utils::get_metadata(&mint)?;
 
-        utils::validate_collection(&token_metadata.additional_metadata, self.collection.key())?;
+        utils::validate_collection(
+            &mint,
+            &token_metadata.additional_metadata,
+            self.collection.key(),
+        )?;
 
         utils::validate_weight(&token_metadata.additional_metadata)?;
 
@@ -0,0 +1,366 @@
+use anchor_lang::{prelude::*, system_program as system};
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        ASSET_CLASS_TAG, COLLECTION_TAG, FEES_COLLECTOR_TAG, LINEAGE_TAG, MAX_MERGE_SIZE, MINT_TAG,
+        NFT_MANAGER_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        fees_collector::FeesCollector,
+        lineage::{Lineage, Provenance},
+        nft_manager::NFTManager,
+        reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+/// Remaining accounts are passed as `[mint, token_account]` pairs, one per merged discriminant,
+/// followed by the lineage of the original the merged NFTs were all cut from.
+#[derive(Accounts)]
+pub struct MergeNFTs<'info> {
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &nft_manager.discriminant.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = new_mint,
+        mint::freeze_authority = new_mint,
+        extensions::metadata_pointer::metadata_address = new_mint,
+        extensions::metadata_pointer::authority = new_mint,
+        extensions::group_member_pointer::authority = new_mint,
+        extensions::group_member_pointer::member_address = new_mint,
+        extensions::close_authority::authority = new_mint,
+    )]
+    pub new_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = new_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub new_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, asset_class.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+}
+
+impl<'info> MergeNFTs<'info> {
+    pub fn merge_nfts(
+        &mut self,
+        bumps: &MergeNFTsBumps,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        args: MergeNFTsArgs,
+    ) -> Result<()> {
+        let signer = self.signer.to_account_info();
+        let system_program = self.system_program.to_account_info();
+        let token_program = self.token_program.to_account_info();
+        let metal = self.asset_class.metal;
+        let collection_key = self.collection.key();
+        let count = args.discriminants.len();
+
+        require!(
+            count >= 2 && count <= MAX_MERGE_SIZE as usize,
+            NFTManagerError::InvalidBatchSize
+        );
+        require_gte!(
+            remaining_accounts.len(),
+            count * 2,
+            NFTManagerError::InvalidBatchSize
+        );
+
+        let mut sorted_discriminants = args.discriminants.clone();
+        sorted_discriminants.sort_unstable();
+        sorted_discriminants.dedup();
+        require_eq!(
+            sorted_discriminants.len(),
+            count,
+            NFTManagerError::InvalidMint
+        );
+
+        let mut merged_mints = Vec::with_capacity(count);
+        let mut provenances = Vec::with_capacity(count);
+        let mut total_weight: u64 = 0;
+        let mut fineness: Option<u16> = None;
+        let mut bar_serial: Option<Option<String>> = None;
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        for (i, discriminant) in args.discriminants.iter().enumerate() {
+            let mint = &remaining_accounts[i * 2];
+            let token_account = &remaining_accounts[i * 2 + 1];
+
+            let (mint_key, mint_bump) = Pubkey::find_program_address(
+                &[
+                    MINT_TAG,
+                    collection_id.as_bytes(),
+                    &discriminant.to_le_bytes(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(mint.key(), mint_key, NFTManagerError::InvalidMint);
+
+            let mint_data = InterfaceAccount::<token_interface::Mint>::try_from(mint)?;
+            let token_account_data =
+                InterfaceAccount::<token_interface::TokenAccount>::try_from(token_account)?;
+            require!(
+                token_account_data.mint == mint_key
+                    && token_account_data.owner == signer.key()
+                    && mint_data.supply > 0
+                    && token_account_data.amount == mint_data.supply,
+                NFTManagerError::InvalidTokenAccount
+            );
+
+            let token_metadata = utils::get_metadata(mint)?;
+            let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+            utils::validate_metal(&token_metadata.additional_metadata, metal)?;
+            utils::validate_collection(mint, &token_metadata.additional_metadata, collection_key)?;
+            // A merged NFT has a single parent, so only pieces of the same original merge
+            let provenance = utils::get_provenance(mint_key, &token_metadata.additional_metadata)?;
+            require!(
+                provenances
+                    .iter()
+                    .all(|merged: &Provenance| merged.root == provenance.root),
+                NFTManagerError::MixedLineage
+            );
+            provenances.push(provenance);
+
+            require!(
+                *fineness.get_or_insert(weight.fineness) == weight.fineness,
+                NFTManagerError::FinenessMismatch
+            );
+            // Only pieces of the same bar, or NFTs backed by no bar, merge together
+            let serial = utils::get_bar_serial(&token_metadata.additional_metadata);
+            require!(
+                bar_serial
+                    .get_or_insert_with(|| serial.map(str::to_string))
+                    .as_deref()
+                    == serial,
+                NFTManagerError::BarMismatch
+            );
+
+            total_weight = total_weight
+                .checked_add(weight.milligrams)
+                .ok_or(NFTManagerError::Overflow)?;
+
+            let mint_seeds = &[
+                MINT_TAG,
+                collection_id.as_bytes(),
+                &discriminant.to_le_bytes()[..],
+                &[mint_bump],
+            ];
+            let mint_signer_seeds = &[&mint_seeds[..]];
+
+            token_interface::burn(
+                CpiContext::new(
+                    token_program.clone(),
+                    token_interface::Burn {
+                        mint: mint.clone(),
+                        from: token_account.clone(),
+                        authority: signer.clone(),
+                    },
+                ),
+                mint_data.supply,
+            )?;
+
+            token_interface::close_account(CpiContext::new(
+                token_program.clone(),
+                token_interface::CloseAccount {
+                    account: token_account.clone(),
+                    destination: signer.clone(),
+                    authority: signer.clone(),
+                },
+            ))?;
+
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::CloseAccount {
+                    account: mint.clone(),
+                    destination: signer.clone(),
+                    authority: mint.clone(),
+                },
+                mint_signer_seeds,
+            ))?;
+
+            merged_mints.push(mint_key);
+        }
+
+        let root = provenances[0].root;
+        require_eq!(
+            remaining_accounts.len(),
+            count * 2 + 1,
+            NFTManagerError::InvalidBatchSize
+        );
+
+        let fee = self.fees_collector.merge_fee;
+
+        if fee > 0 {
+            system::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system::Transfer {
+                        from: signer.clone(),
+                        to: self.fees_collector.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+
+        let weight = utils::Weight::new(
+            total_weight,
+            fineness.ok_or(NFTManagerError::InvalidWeight)?,
+        )?;
+        self.asset_class.validate_weight(weight.milligrams)?;
+        let discriminant = self.nft_manager.discriminant;
+        let new_mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes()[..],
+            &[bumps.new_mint],
+        ];
+        let new_mint_signer_seeds = &[&new_mint_seeds[..]];
+
+        utils::mint_nft_with_metadata(
+            args.name,
+            args.symbol,
+            args.uri,
+            &weight,
+            metal,
+            discriminant,
+            new_mint_signer_seeds,
+            &mut self.nft_manager,
+            self.new_mint.to_account_info(),
+            self.new_token_account.to_account_info(),
+            self.collection.to_account_info(),
+            bumps.collection,
+            token_program.clone(),
+            system_program.clone(),
+            signer.clone(),
+        )?;
+
+        let provenance = utils::get_provenance(root, &[])?.child(root)?;
+
+        let lineage_info = &remaining_accounts[count * 2];
+        let (lineage_key, _) =
+            Pubkey::find_program_address(&[LINEAGE_TAG, root.as_ref()], &crate::ID);
+        require_keys_eq!(
+            lineage_info.key(),
+            lineage_key,
+            NFTManagerError::InvalidLineage
+        );
+
+        // Two NFTs of the same original include at least one piece, so the lineage exists
+        let mut lineage = Account::<Lineage>::try_from(lineage_info)?;
+
+        // Burned pieces stay recorded with no weight left
+        for mint in merged_mints.iter() {
+            lineage.update_weight(*mint, 0)?;
+        }
+        lineage.record(self.new_mint.key(), &provenance, weight.milligrams)?;
+        utils::realloc_account(
+            lineage_info.clone(),
+            signer.clone(),
+            system_program.clone(),
+            8 + Lineage::calculate_space(lineage.entries.len()),
+        )?;
+        lineage.exit(&crate::ID)?;
+
+        let mut fields = utils::provenance_metadata(&provenance);
+        fields.extend(utils::bar_serial_metadata(bar_serial.flatten().as_deref()));
+        utils::update_additional_metadata(
+            self.new_mint.to_account_info(),
+            system_program,
+            token_program,
+            signer,
+            fields,
+            new_mint_signer_seeds,
+        )?;
+
+        // The merged NFTs were already counted, so the entry never grows here
+        self.reserve_ledger.record_merge(
+            self.nft_manager.collection,
+            metal,
+            args.discriminants.len() as u64,
+        )?;
+
+        emit!(MergeNFTsEvent {
+            new_mint: self.new_mint.key(),
+            discriminant,
+            merged_mints,
+            merged_discriminants: args.discriminants,
+            owner: self.signer.key(),
+            fee,
+            metal,
+            weight: weight.milligrams,
+            fineness: weight.fineness,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MergeNFTsArgs {
+    /// Discriminants of the NFTs to merge, in the order of the remaining accounts
+    pub discriminants: Vec<u64>,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct MergeNFTsEvent {
+    pub new_mint: Pubkey,
+    pub discriminant: u64,
+    pub merged_mints: Vec<Pubkey>,
+    pub merged_discriminants: Vec<u64>,
+    pub owner: Pubkey,
+    pub fee: u64,
+    pub metal: Metal,
+    pub weight: u64,
+    pub fineness: u16,
+}
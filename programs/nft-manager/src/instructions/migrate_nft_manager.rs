@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEES_COLLECTOR_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{fees_collector::FeesCollector, nft_manager::NFTManager},
+    utils,
+};
+
+#[derive(Accounts)]
+pub struct MigrateNFTManager<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: Deserialized once resized to the current layout
+    pub nft_manager: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEES_COLLECTOR_TAG],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: Deserialized once resized to the current layout
+    pub fees_collector: UncheckedAccount<'info>,
+}
+
+impl<'info> MigrateNFTManager<'info> {
+    /// Resizes the singletons created before collections were scoped and fees were extended. The
+    /// manager is rewritten as the default collection, and the bytes appended to the fees
+    /// collector are zeroed, which reads back as no mint premium, fabrication fee, merge fee or
+    /// cancel refund. The oracle config and finalize index are created with their own instructions
+    /// afterwards.
+    pub fn migrate_nft_manager(&mut self) -> Result<()> {
+        let nft_manager = self.nft_manager.to_account_info();
+        let fees_collector = self.fees_collector.to_account_info();
+
+        self.grow(nft_manager.clone(), 8 + NFTManager::INIT_SPACE)?;
+        self.grow(fees_collector.clone(), 8 + FeesCollector::INIT_SPACE)?;
+
+        let data = NFTManager::migrate(&mut nft_manager.try_borrow_mut_data()?)?;
+
+        require_keys_eq!(
+            data.authority,
+            self.signer.key(),
+            NFTManagerError::OnlyAdminAllowed
+        );
+        require!(
+            data.collection_id.is_empty(),
+            NFTManagerError::InvalidCollectionId
+        );
+
+        FeesCollector::try_deserialize(&mut &fees_collector.try_borrow_data()?[..])?;
+
+        Ok(())
+    }
+
+    fn grow(&self, account: AccountInfo<'info>, new_len: usize) -> Result<()> {
+        let old_len = account.data_len();
+
+        utils::realloc_account(
+            account.clone(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            new_len,
+        )?;
+
+        if new_len > old_len {
+            account.try_borrow_mut_data()?[old_len..].fill(0);
+        }
+
+        Ok(())
+    }
+}
@@ -1,20 +1,39 @@
 use anchor_lang::{prelude::*, system_program as system};
 use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
-    constants::{FINALIZE_MINT_DATA_TAG, MINT_FEES_COLLECTOR_TAG, MINT_TAG, NFT_MANAGER_TAG},
+    constants::{
+        ASSET_CLASS_TAG, ATTESTATION_TAG, BAR_RECORD_TAG, BAR_SERIAL_KEY, COLLECTION_TAG,
+        FEES_COLLECTOR_TAG, FINALIZE_INDEX_TAG, FINALIZE_MINT_DATA_TAG, MINT_FEES_COLLECTOR_TAG,
+        MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
     states::{
-        finalize_mint_data::FinalizeMintData, mint_fees_collector::MintFeesCollector,
+        asset_class::{AssetClass, Metal},
+        attestation::Attestation,
+        bar_record::BarRecord,
+        fees_collector::FeesCollector,
+        finalize_index::{FinalizeIndex, FinalizeKind, PendingFinalization},
+        finalize_mint_data::FinalizeMintData,
+        mint_fees_collector::MintFeesCollector,
         nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+        reserve_ledger::ReserveLedger,
     },
     utils,
 };
 
 #[derive(Accounts)]
+#[instruction(args: MintNFTArgs)]
 pub struct MintNFT<'info> {
-    pub gold_price_update: Box<Account<'info, PriceUpdateV2>>,
-    pub sol_price_update: Box<Account<'info, PriceUpdateV2>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
@@ -25,7 +44,7 @@ pub struct MintNFT<'info> {
     #[account(
         init,
         payer = signer,
-        seeds = [MINT_TAG, &nft_manager.discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &nft_manager.discriminant.to_le_bytes()],
         bump,
         mint::decimals = 0,
         mint::authority = mint,
@@ -50,6 +69,14 @@ pub struct MintNFT<'info> {
     )]
     pub recipient_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = signer,
@@ -61,17 +88,63 @@ pub struct MintNFT<'info> {
 
     #[account(
         mut,
-        seeds = [MINT_FEES_COLLECTOR_TAG],
+        seeds = [MINT_FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     pub mint_fees_collector: Box<Account<'info, MintFeesCollector>>,
 
     #[account(
         mut,
-        seeds = [NFT_MANAGER_TAG],
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    #[account(
+        seeds = [ATTESTATION_TAG, args.metal.seed()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, bar_record.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Box<Account<'info, BarRecord>>,
 }
 
 impl<'info> MintNFT<'info> {
@@ -82,12 +155,33 @@ impl<'info> MintNFT<'info> {
         let finalize_data = self.finalize_data.as_mut();
         let discriminant = self.nft_manager.discriminant;
 
-        let price_in_lamports = utils::get_gold_value_in_lamport(
-            &self.gold_price_update,
-            &self.sol_price_update,
-            args.weight,
+        let weight = utils::Weight::new(args.weight, args.fineness)?;
+        self.asset_class.validate_weight(weight.milligrams)?;
+
+        let price_in_lamports = utils::get_metal_value_in_lamport(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
         )?;
 
+        let premium = self.fees_collector.calc_mint_premium(price_in_lamports)?;
+        let fabrication_fee = self.fees_collector.fabrication_fee;
+
+        let total = price_in_lamports
+            .checked_add(premium)
+            .and_then(|v| v.checked_add(fabrication_fee))
+            .ok_or(NFTManagerError::Overflow)?;
+
+        require_gte!(args.max_lamports, total, NFTManagerError::SlippageExceeded);
+
         system::transfer(
             CpiContext::new(
                 system_program.clone(),
@@ -99,7 +193,30 @@ impl<'info> MintNFT<'info> {
             price_in_lamports,
         )?;
 
-        let mint_seeds = &[MINT_TAG, &discriminant.to_le_bytes()[..], &[bumps.mint]];
+        let fees = premium
+            .checked_add(fabrication_fee)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        if fees > 0 {
+            system::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system::Transfer {
+                        from: signer.clone(),
+                        to: self.fees_collector.to_account_info(),
+                    },
+                ),
+                fees,
+            )?;
+        }
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes()[..],
+            &[bumps.mint],
+        ];
         let mint_signer_seeds = &[&mint_seeds[..]];
 
         utils::mint_nft(
@@ -107,24 +224,69 @@ impl<'info> MintNFT<'info> {
             args.symbol,
             args.uri,
             args.weight,
+            args.metal,
             discriminant,
             mint_signer_seeds,
             &mut self.nft_manager,
             self.mint.to_account_info(),
             self.recipient_token_account.to_account_info(),
+            self.collection.to_account_info(),
+            bumps.collection,
             self.token_program.to_account_info(),
             system_program,
             signer,
         )?;
 
-        finalize_data.init(mint_key, args.weight, bumps.finalize_data)?;
+        finalize_data.init(
+            mint_key,
+            weight.milligrams,
+            weight.fineness,
+            self.signer.key(),
+            bumps.finalize_data,
+        )?;
+
+        self.finalize_index.add(PendingFinalization {
+            finalize_data: finalize_data.key(),
+            mint: mint_key,
+            kind: FinalizeKind::Mint,
+            created_at: finalize_data.created_at,
+        })?;
+
+        self.bar_record.assign(args.metal, &weight)?;
+        utils::update_additional_metadata(
+            self.mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            vec![(BAR_SERIAL_KEY.to_string(), self.bar_record.serial.clone())],
+            mint_signer_seeds,
+        )?;
+
+        self.reserve_ledger.record_mint(
+            self.nft_manager.collection,
+            args.metal,
+            weight.milligrams,
+        )?;
+        self.attestation
+            .validate_reserve(self.reserve_ledger.outstanding_weight_of(args.metal)?)?;
+        utils::realloc_account(
+            self.reserve_ledger.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + ReserveLedger::calculate_space(self.reserve_ledger.collections.len()),
+        )?;
 
         emit!(MintNFTEvent {
             mint: mint_key,
             finalize_data: finalize_data.key(),
             recipient: self.recipient.key(),
+            quoted: args.max_lamports,
+            charged: total,
             price: price_in_lamports,
+            premium,
+            fabrication_fee,
             discriminant,
+            metal: args.metal,
         });
 
         Ok(())
@@ -136,7 +298,26 @@ pub struct MintNFTArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// Gross weight in milligrams
+    pub weight: u64,
+    /// Parts per ten thousand of pure metal, e.g. 9999
+    pub fineness: u16,
+    pub metal: Metal,
+    /// Most lamports the signer accepts to pay
+    pub max_lamports: u64,
+}
+
+/// One NFT of a fractionalize or batch mint, the slippage limit covers the whole instruction.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct NFTPartArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    /// Gross weight in milligrams
     pub weight: u64,
+    /// Parts per ten thousand of pure metal, e.g. 9999
+    pub fineness: u16,
+    pub metal: Metal,
 }
 
 #[event]
@@ -144,6 +325,13 @@ pub struct MintNFTEvent {
     pub mint: Pubkey,
     pub finalize_data: Pubkey,
     pub recipient: Pubkey,
+    /// Most lamports the signer accepted to pay
+    pub quoted: u64,
+    /// Lamports charged, spot price plus premium and fabrication fee
+    pub charged: u64,
     pub price: u64,
+    pub premium: u64,
+    pub fabrication_fee: u64,
     pub discriminant: u64,
+    pub metal: Metal,
 }
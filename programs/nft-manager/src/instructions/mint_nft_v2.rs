@@ -0,0 +1,280 @@
+use anchor_lang::{prelude::*, system_program as system};
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        ASSET_CLASS_TAG, ATTESTATION_TAG, BAR_RECORD_TAG, BAR_SERIAL_KEY, COLLECTION_TAG,
+        FEES_COLLECTOR_TAG, MINT_FEES_COLLECTOR_TAG, MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG,
+        RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        attestation::Attestation,
+        bar_record::BarRecord,
+        fees_collector::FeesCollector,
+        mint_fees_collector::MintFeesCollector,
+        nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+        reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+use super::MintNFTArgs;
+
+#[derive(Accounts)]
+#[instruction(args: MintNFTArgs)]
+pub struct MintNFTV2<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &nft_manager.discriminant.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint,
+        mint::freeze_authority = mint,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::metadata_pointer::authority = mint,
+        extensions::group_member_pointer::authority = mint,
+        extensions::group_member_pointer::member_address = mint,
+        extensions::close_authority::authority = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    /// CHECK: This account will receive the token
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MINT_FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub mint_fees_collector: Box<Account<'info, MintFeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    #[account(
+        seeds = [ATTESTATION_TAG, args.metal.seed()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, bar_record.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Box<Account<'info, BarRecord>>,
+}
+
+impl<'info> MintNFTV2<'info> {
+    pub fn mint_nft_v2(&mut self, bumps: &MintNFTV2Bumps, args: MintNFTArgs) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer = self.signer.to_account_info();
+        let system_program = self.system_program.to_account_info();
+        let discriminant = self.nft_manager.discriminant;
+
+        let weight = utils::Weight::new(args.weight, args.fineness)?;
+        self.asset_class.validate_weight(weight.milligrams)?;
+
+        let price_in_lamports = utils::get_metal_value_in_lamport(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
+        )?;
+
+        let premium = self.fees_collector.calc_mint_premium(price_in_lamports)?;
+        let fabrication_fee = self.fees_collector.fabrication_fee;
+
+        let total = price_in_lamports
+            .checked_add(premium)
+            .and_then(|v| v.checked_add(fabrication_fee))
+            .ok_or(NFTManagerError::Overflow)?;
+
+        require_gte!(args.max_lamports, total, NFTManagerError::SlippageExceeded);
+
+        system::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system::Transfer {
+                    from: signer.clone(),
+                    to: self.mint_fees_collector.to_account_info(),
+                },
+            ),
+            price_in_lamports,
+        )?;
+
+        let fees = premium
+            .checked_add(fabrication_fee)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        if fees > 0 {
+            system::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system::Transfer {
+                        from: signer.clone(),
+                        to: self.fees_collector.to_account_info(),
+                    },
+                ),
+                fees,
+            )?;
+        }
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes()[..],
+            &[bumps.mint],
+        ];
+        let mint_signer_seeds = &[&mint_seeds[..]];
+
+        utils::mint_nft_with_metadata(
+            args.name,
+            args.symbol,
+            args.uri,
+            &weight,
+            args.metal,
+            discriminant,
+            mint_signer_seeds,
+            &mut self.nft_manager,
+            self.mint.to_account_info(),
+            self.recipient_token_account.to_account_info(),
+            self.collection.to_account_info(),
+            bumps.collection,
+            self.token_program.to_account_info(),
+            system_program,
+            signer,
+        )?;
+
+        self.bar_record.assign(args.metal, &weight)?;
+        utils::update_additional_metadata(
+            self.mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            vec![(BAR_SERIAL_KEY.to_string(), self.bar_record.serial.clone())],
+            mint_signer_seeds,
+        )?;
+
+        self.reserve_ledger.record_mint(
+            self.nft_manager.collection,
+            args.metal,
+            weight.milligrams,
+        )?;
+        self.attestation
+            .validate_reserve(self.reserve_ledger.outstanding_weight_of(args.metal)?)?;
+        utils::realloc_account(
+            self.reserve_ledger.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + ReserveLedger::calculate_space(self.reserve_ledger.collections.len()),
+        )?;
+
+        emit!(MintNFTV2Event {
+            mint: mint_key,
+            recipient: self.recipient.key(),
+            quoted: args.max_lamports,
+            charged: total,
+            price: price_in_lamports,
+            premium,
+            fabrication_fee,
+            discriminant,
+            metal: args.metal,
+            weight: weight.milligrams,
+            fineness: weight.fineness,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct MintNFTV2Event {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    /// Most lamports the signer accepted to pay
+    pub quoted: u64,
+    /// Lamports charged, spot price plus premium and fabrication fee
+    pub charged: u64,
+    pub price: u64,
+    pub premium: u64,
+    pub fabrication_fee: u64,
+    pub discriminant: u64,
+    pub metal: Metal,
+    pub weight: u64,
+    pub fineness: u16,
+}
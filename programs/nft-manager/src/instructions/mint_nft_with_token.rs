@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        ASSET_CLASS_TAG, ATTESTATION_TAG, BAR_RECORD_TAG, BAR_SERIAL_KEY, COLLECTION_TAG,
+        FINALIZE_INDEX_TAG, FINALIZE_MINT_DATA_TAG, MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG,
+        PAYMENT_TOKEN_TAG, PAYMENT_VAULT_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        attestation::Attestation,
+        bar_record::BarRecord,
+        finalize_index::{FinalizeIndex, FinalizeKind, PendingFinalization},
+        finalize_mint_data::FinalizeMintData,
+        nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+        payment_token::PaymentToken,
+        reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(args: MintNFTWithTokenArgs)]
+pub struct MintNFTWithToken<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+    #[account(
+        address = payment_token.token_program @NFTManagerError::InvalidTokenAccount,
+    )]
+    pub payment_token_program: Interface<'info, token_interface::TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &nft_manager.discriminant.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint,
+        mint::freeze_authority = mint,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::metadata_pointer::authority = mint,
+        extensions::group_member_pointer::authority = mint,
+        extensions::group_member_pointer::member_address = mint,
+        extensions::close_authority::authority = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    /// CHECK: This account will receive the token
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        address = payment_token.mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = signer,
+        token::token_program = payment_token_program,
+    )]
+    pub signer_payment_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [PAYMENT_TOKEN_TAG, payment_mint.key().as_ref()],
+        bump = payment_token.bump,
+        constraint = payment_token.enabled @NFTManagerError::PaymentTokenDisabled,
+    )]
+    pub payment_token: Box<Account<'info, PaymentToken>>,
+
+    #[account(
+        mut,
+        seeds = [PAYMENT_VAULT_TAG, payment_mint.key().as_ref()],
+        bump = payment_token.vault_bump,
+    )]
+    pub vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FinalizeMintData::INIT_SPACE,
+        seeds = [FINALIZE_MINT_DATA_TAG, mint.key().as_ref()],
+        bump,
+    )]
+    pub finalize_data: Box<Account<'info, FinalizeMintData>>,
+
+    #[account(
+        mut,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [FINALIZE_INDEX_TAG],
+        bump = finalize_index.bump,
+    )]
+    pub finalize_index: Box<Account<'info, FinalizeIndex>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    #[account(
+        seeds = [ATTESTATION_TAG, args.metal.seed()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, bar_record.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Box<Account<'info, BarRecord>>,
+}
+
+impl<'info> MintNFTWithToken<'info> {
+    pub fn mint_nft_with_token(
+        &mut self,
+        bumps: &MintNFTWithTokenBumps,
+        args: MintNFTWithTokenArgs,
+    ) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer = self.signer.to_account_info();
+        let finalize_data = self.finalize_data.as_mut();
+        let discriminant = self.nft_manager.discriminant;
+
+        let weight = utils::Weight::new(args.weight, args.fineness)?;
+        self.asset_class.validate_weight(weight.milligrams)?;
+
+        let price = utils::get_metal_value_in_token(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
+            self.payment_mint.decimals,
+        )?;
+
+        require_gte!(args.max_amount, price, NFTManagerError::SlippageExceeded);
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                self.payment_token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    mint: self.payment_mint.to_account_info(),
+                    from: self.signer_payment_token_account.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: signer.clone(),
+                },
+            ),
+            price,
+            self.payment_mint.decimals,
+        )?;
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &discriminant.to_le_bytes()[..],
+            &[bumps.mint],
+        ];
+        let mint_signer_seeds = &[&mint_seeds[..]];
+
+        utils::mint_nft(
+            args.name,
+            args.symbol,
+            args.uri,
+            args.weight,
+            args.metal,
+            discriminant,
+            mint_signer_seeds,
+            &mut self.nft_manager,
+            self.mint.to_account_info(),
+            self.recipient_token_account.to_account_info(),
+            self.collection.to_account_info(),
+            bumps.collection,
+            self.token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            signer,
+        )?;
+
+        finalize_data.init(
+            mint_key,
+            weight.milligrams,
+            weight.fineness,
+            self.signer.key(),
+            bumps.finalize_data,
+        )?;
+
+        self.finalize_index.add(PendingFinalization {
+            finalize_data: finalize_data.key(),
+            mint: mint_key,
+            kind: FinalizeKind::Mint,
+            created_at: finalize_data.created_at,
+        })?;
+
+        self.bar_record.assign(args.metal, &weight)?;
+        utils::update_additional_metadata(
+            self.mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.signer.to_account_info(),
+            vec![(BAR_SERIAL_KEY.to_string(), self.bar_record.serial.clone())],
+            mint_signer_seeds,
+        )?;
+
+        self.reserve_ledger.record_mint(
+            self.nft_manager.collection,
+            args.metal,
+            weight.milligrams,
+        )?;
+        self.attestation
+            .validate_reserve(self.reserve_ledger.outstanding_weight_of(args.metal)?)?;
+        utils::realloc_account(
+            self.reserve_ledger.to_account_info(),
+            self.signer.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + ReserveLedger::calculate_space(self.reserve_ledger.collections.len()),
+        )?;
+
+        emit!(MintNFTWithTokenEvent {
+            mint: mint_key,
+            finalize_data: finalize_data.key(),
+            recipient: self.recipient.key(),
+            payment_mint: self.payment_mint.key(),
+            quoted: args.max_amount,
+            charged: price,
+            price,
+            discriminant,
+            metal: args.metal,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct MintNFTWithTokenArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    /// Gross weight in milligrams
+    pub weight: u64,
+    /// Parts per ten thousand of pure metal, e.g. 9999
+    pub fineness: u16,
+    pub metal: Metal,
+    /// Most payment token base units the signer accepts to pay
+    pub max_amount: u64,
+}
+
+#[event]
+pub struct MintNFTWithTokenEvent {
+    pub mint: Pubkey,
+    pub finalize_data: Pubkey,
+    pub recipient: Pubkey,
+    pub payment_mint: Pubkey,
+    /// Most payment token base units the signer accepted to pay
+    pub quoted: u64,
+    /// Payment token base units charged
+    pub charged: u64,
+    pub price: u64,
+    pub discriminant: u64,
+    pub metal: Metal,
+}
@@ -1,39 +1,117 @@
 pub mod admin_withdraw_fees;
 pub mod admin_withdraw_mint_fees;
+pub mod admin_withdraw_payment_token;
+pub mod batch_mint_nft;
 pub mod burn_nft;
 pub mod buy_nft;
+pub mod cancel_fractionalize;
+pub mod crank_finalize_fractionalize;
+pub mod crank_finalize_mint;
+pub mod create_asset_class;
 pub mod create_collection;
+pub mod create_gram_vault;
+pub mod create_payment_token;
 pub mod create_user_account;
 pub mod delist_nft;
+pub mod deposit_to_gram_vault;
 pub mod finalize_fractionalize_nft;
+pub mod finalize_fractionalize_nft_batch;
 pub mod finalize_mint_nft;
 pub mod finalize_ownership_transfer;
 pub mod fractionalize_nft;
+pub mod fulfill_redemption;
+pub mod initialize_attestation;
+pub mod initialize_bar_registry;
+pub mod initialize_collection_manager;
+pub mod initialize_finalize_index;
 pub mod initialize_nft_manager;
+pub mod initialize_oracle_config;
 pub mod initialize_ownership_transfer;
+pub mod initialize_reserve_ledger;
 pub mod list_nft;
+pub mod merge_nfts;
+pub mod migrate_nft_manager;
 pub mod mint_nft;
+pub mod mint_nft_v2;
+pub mod mint_nft_with_token;
+pub mod post_attestation;
+pub mod quote_buy;
+pub mod quote_fractionalize;
+pub mod quote_mint;
+pub mod register_bar;
+pub mod reject_redemption;
+pub mod request_redemption;
+pub mod sell_back_nft;
+pub mod set_admin_price;
+pub mod set_buyback_config;
+pub mod update_asset_class;
+pub mod update_attestation_config;
+pub mod update_bar;
+pub mod update_bar_operator;
 pub mod update_fees;
 pub mod update_listing_price;
 pub mod update_metadata;
+pub mod update_oracle_config;
+pub mod update_payment_token;
 pub mod user_withdraw;
+pub mod withdraw_buyback_inventory;
+pub mod withdraw_from_gram_vault;
 
 pub use admin_withdraw_fees::*;
 pub use admin_withdraw_mint_fees::*;
+pub use admin_withdraw_payment_token::*;
+pub use batch_mint_nft::*;
 pub use burn_nft::*;
 pub use buy_nft::*;
+pub use cancel_fractionalize::*;
+pub use crank_finalize_fractionalize::*;
+pub use crank_finalize_mint::*;
+pub use create_asset_class::*;
 pub use create_collection::*;
+pub use create_gram_vault::*;
+pub use create_payment_token::*;
 pub use create_user_account::*;
 pub use delist_nft::*;
+pub use deposit_to_gram_vault::*;
 pub use finalize_fractionalize_nft::*;
+pub use finalize_fractionalize_nft_batch::*;
 pub use finalize_mint_nft::*;
 pub use finalize_ownership_transfer::*;
 pub use fractionalize_nft::*;
+pub use fulfill_redemption::*;
+pub use initialize_attestation::*;
+pub use initialize_bar_registry::*;
+pub use initialize_collection_manager::*;
+pub use initialize_finalize_index::*;
 pub use initialize_nft_manager::*;
+pub use initialize_oracle_config::*;
 pub use initialize_ownership_transfer::*;
+pub use initialize_reserve_ledger::*;
 pub use list_nft::*;
+pub use merge_nfts::*;
+pub use migrate_nft_manager::*;
 pub use mint_nft::*;
+pub use mint_nft_v2::*;
+pub use mint_nft_with_token::*;
+pub use post_attestation::*;
+pub use quote_buy::*;
+pub use quote_fractionalize::*;
+pub use quote_mint::*;
+pub use register_bar::*;
+pub use reject_redemption::*;
+pub use request_redemption::*;
+pub use sell_back_nft::*;
+pub use set_admin_price::*;
+pub use set_buyback_config::*;
+pub use update_asset_class::*;
+pub use update_attestation_config::*;
+pub use update_bar::*;
+pub use update_bar_operator::*;
 pub use update_fees::*;
 pub use update_listing_price::*;
 pub use update_metadata::*;
+pub use update_oracle_config::*;
+pub use update_payment_token::*;
 pub use user_withdraw::*;
+pub use withdraw_buyback_inventory::*;
+pub use withdraw_from_gram_vault::*;
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::ATTESTATION_TAG,
+    errors::NFTManagerError,
+    states::{asset_class::Metal, attestation::Attestation},
+};
+
+#[derive(Accounts)]
+#[instruction(args: PostAttestationArgs)]
+pub struct PostAttestation<'info> {
+    #[account(address = attestation.auditor @NFTManagerError::OnlyAuditorAllowed)]
+    pub auditor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ATTESTATION_TAG, args.metal.seed()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+}
+
+impl<'info> PostAttestation<'info> {
+    pub fn post_attestation(&mut self, args: PostAttestationArgs) -> Result<()> {
+        self.attestation
+            .post(args.attested_weight, args.report_hash, args.timestamp)?;
+
+        emit!(PostAttestationEvent {
+            attestation: self.attestation.key(),
+            auditor: self.auditor.key(),
+            metal: args.metal,
+            attested_weight: args.attested_weight,
+            report_hash: args.report_hash,
+            timestamp: args.timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct PostAttestationArgs {
+    pub metal: Metal,
+    /// Gross weight in milligrams held in the vault
+    pub attested_weight: u64,
+    pub report_hash: [u8; 32],
+    /// Time the vault was counted
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostAttestationEvent {
+    pub attestation: Pubkey,
+    pub auditor: Pubkey,
+    pub metal: Metal,
+    pub attested_weight: u64,
+    pub report_hash: [u8; 32],
+    pub timestamp: i64,
+}
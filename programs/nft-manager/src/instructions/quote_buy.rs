@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    constants::{FEES_COLLECTOR_TAG, LISTING_TAG, MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG},
+    errors::NFTManagerError,
+    states::{
+        fees_collector::FeesCollector, listing::Listing, nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+    },
+    utils,
+};
+
+use super::Quote;
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64, seller: Pubkey)]
+pub struct QuoteBuy<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        seeds = [LISTING_TAG, mint.key().as_ref(), seller.as_ref()],
+        bump,
+        has_one = mint @NFTManagerError::InvalidListing,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> QuoteBuy<'info> {
+    pub fn quote_buy(&self) -> Result<Quote> {
+        let sol_price = utils::get_sol_price(
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+        )?;
+
+        let lamports = utils::calc_usd_value_in_lamport(self.listing.price, sol_price)?;
+
+        let fee = self
+            .fees_collector
+            .calc_fee(lamports, self.fees_collector.sell_fee)?;
+
+        Ok(Quote {
+            lamports,
+            fee,
+            metal_publish_time: None,
+            sol_publish_time: sol_price.publish_time,
+        })
+    }
+}
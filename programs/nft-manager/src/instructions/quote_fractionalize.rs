@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    constants::{
+        ASSET_CLASS_TAG, FEES_COLLECTOR_TAG, MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG,
+    },
+    states::{
+        asset_class::AssetClass, fees_collector::FeesCollector, nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+    },
+    utils,
+};
+
+use super::Quote;
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct QuoteFractionalize<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, asset_class.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> QuoteFractionalize<'info> {
+    pub fn quote_fractionalize(&self) -> Result<Quote> {
+        let token_metadata = utils::get_metadata(&self.mint.to_account_info())?;
+
+        let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+        utils::validate_metal(&token_metadata.additional_metadata, self.asset_class.metal)?;
+
+        let value = utils::get_metal_value(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
+        )?;
+
+        let fee = self
+            .fees_collector
+            .calc_fee(value.lamports, self.fees_collector.fractionalize_fee)?;
+
+        Ok(Quote {
+            lamports: value.lamports,
+            fee,
+            metal_publish_time: Some(value.metal_publish_time),
+            sol_publish_time: value.sol_publish_time,
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ASSET_CLASS_TAG, FEES_COLLECTOR_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG},
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        fees_collector::FeesCollector,
+        nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+    },
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(args: QuoteMintArgs)]
+pub struct QuoteMint<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub fees_collector: Box<Account<'info, FeesCollector>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> QuoteMint<'info> {
+    pub fn quote_mint(&self, args: QuoteMintArgs) -> Result<Quote> {
+        let weight = utils::Weight::new(args.weight, args.fineness)?;
+        self.asset_class.validate_weight(weight.milligrams)?;
+
+        let value = utils::get_metal_value(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
+        )?;
+
+        let fee = self
+            .fees_collector
+            .calc_mint_premium(value.lamports)?
+            .checked_add(self.fees_collector.fabrication_fee)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(Quote {
+            lamports: value.lamports,
+            fee,
+            metal_publish_time: Some(value.metal_publish_time),
+            sol_publish_time: value.sol_publish_time,
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QuoteMintArgs {
+    /// Gross weight in milligrams
+    pub weight: u64,
+    /// Parts per ten thousand of pure metal, e.g. 9999
+    pub fineness: u16,
+    pub metal: Metal,
+}
+
+/// Price quote returned by the quote instructions
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct Quote {
+    /// Lamport value of the nft or listing
+    pub lamports: u64,
+    /// Lamports charged by the protocol on top of the value, if any
+    pub fee: u64,
+    pub metal_publish_time: Option<i64>,
+    pub sol_publish_time: i64,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BAR_RECORD_TAG, BAR_REGISTRY_TAG},
+    errors::NFTManagerError,
+    states::{asset_class::Metal, bar_record::BarRecord, bar_registry::BarRegistry},
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(args: RegisterBarArgs)]
+pub struct RegisterBar<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = bar_registry.operator @NFTManagerError::OnlyOperatorAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BAR_REGISTRY_TAG],
+        bump = bar_registry.bump,
+    )]
+    pub bar_registry: Box<Account<'info, BarRegistry>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + BarRecord::INIT_SPACE,
+        seeds = [BAR_RECORD_TAG, args.serial.as_bytes()],
+        bump
+    )]
+    pub bar_record: Box<Account<'info, BarRecord>>,
+}
+
+impl<'info> RegisterBar<'info> {
+    pub fn register_bar(&mut self, bumps: &RegisterBarBumps, args: RegisterBarArgs) -> Result<()> {
+        let weight = utils::Weight::new(args.gross_weight, args.fineness)?;
+
+        self.bar_record.init(
+            args.serial,
+            args.refiner,
+            args.vault_location,
+            args.metal,
+            &weight,
+            bumps.bar_record,
+        )?;
+
+        self.bar_registry.bar_count = self
+            .bar_registry
+            .bar_count
+            .checked_add(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        emit!(RegisterBarEvent {
+            bar_record: self.bar_record.key(),
+            serial: self.bar_record.serial.clone(),
+            metal: args.metal,
+            gross_weight: weight.milligrams,
+            fineness: weight.fineness,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RegisterBarArgs {
+    pub serial: String,
+    pub refiner: String,
+    pub vault_location: String,
+    pub metal: Metal,
+    /// Gross weight in milligrams
+    pub gross_weight: u64,
+    /// Parts per ten thousand of pure metal, e.g. 9999
+    pub fineness: u16,
+}
+
+#[event]
+pub struct RegisterBarEvent {
+    pub bar_record: Pubkey,
+    pub serial: String,
+    pub metal: Metal,
+    pub gross_weight: u64,
+    pub fineness: u16,
+}
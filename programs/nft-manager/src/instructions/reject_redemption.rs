@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        BAR_REGISTRY_TAG, MINT_TAG, NFT_MANAGER_TAG, REDEMPTION_REQUEST_TAG,
+        REDEMPTION_TOKEN_ACCOUNT_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        bar_registry::BarRegistry,
+        nft_manager::NFTManager,
+        redemption_request::{RedemptionRequest, RedemptionStatus},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct RejectRedemption<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        mut,
+        address = bar_registry.operator @NFTManagerError::OnlyOperatorAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = redemption_request.owner @NFTManagerError::InvalidRedemptionOwner,
+    )]
+    /// CHECK: Gets the NFT back
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [REDEMPTION_REQUEST_TAG, mint.key().as_ref()],
+        bump = redemption_request.bump,
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequest>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = redemption_token_account,
+        seeds = [REDEMPTION_TOKEN_ACCOUNT_TAG, redemption_request.key().as_ref()],
+        bump
+    )]
+    pub redemption_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [BAR_REGISTRY_TAG],
+        bump = bar_registry.bump,
+    )]
+    pub bar_registry: Box<Account<'info, BarRegistry>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> RejectRedemption<'info> {
+    /// Sends the escrowed NFT back to its owner.
+    pub fn reject_redemption(&mut self, bumps: &RejectRedemptionBumps) -> Result<()> {
+        let token_program = self.token_program.to_account_info();
+        let mint = self.mint.to_account_info();
+        let owner = self.owner.to_account_info();
+        let redemption_token_account = self.redemption_token_account.to_account_info();
+        let redemption_request_key = self.redemption_request.key();
+
+        self.redemption_request
+            .resolve(RedemptionStatus::Rejected)?;
+
+        let redemption_token_account_seeds = &[
+            REDEMPTION_TOKEN_ACCOUNT_TAG,
+            redemption_request_key.as_ref(),
+            &[bumps.redemption_token_account],
+        ];
+        let redemption_token_account_signer_seeds = &[&redemption_token_account_seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::TransferChecked {
+                    from: redemption_token_account.clone(),
+                    mint: mint.clone(),
+                    to: self.owner_token_account.to_account_info(),
+                    authority: redemption_token_account.clone(),
+                },
+                redemption_token_account_signer_seeds,
+            ),
+            1,
+            0,
+        )?;
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program,
+            token_interface::CloseAccount {
+                account: redemption_token_account.clone(),
+                destination: owner.clone(),
+                authority: redemption_token_account,
+            },
+            redemption_token_account_signer_seeds,
+        ))?;
+
+        emit!(RejectRedemptionEvent {
+            redemption_request: redemption_request_key,
+            owner: owner.key(),
+            mint: mint.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct RejectRedemptionEvent {
+    pub redemption_request: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        COLLECTION_TAG, MINT_TAG, NFT_MANAGER_TAG, REDEMPTION_REQUEST_TAG,
+        REDEMPTION_TOKEN_ACCOUNT_TAG,
+    },
+    errors::NFTManagerError,
+    states::{asset_class::Metal, nft_manager::NFTManager, redemption_request::RedemptionRequest},
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(args: RequestRedemptionArgs)]
+pub struct RequestRedemption<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &args.discriminant.to_le_bytes()],
+        bump,
+        constraint = mint.supply == 1 @NFTManagerError::InvalidMintSupply,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + RedemptionRequest::INIT_SPACE,
+        seeds = [REDEMPTION_REQUEST_TAG, mint.key().as_ref()],
+        bump
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequest>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = mint,
+        token::authority = redemption_token_account,
+        seeds = [REDEMPTION_TOKEN_ACCOUNT_TAG, redemption_request.key().as_ref()],
+        bump
+    )]
+    pub redemption_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> RequestRedemption<'info> {
+    /// Escrows the NFT until the operator delivers the metal or turns the request down.
+    pub fn request_redemption(
+        &mut self,
+        bumps: &RequestRedemptionBumps,
+        args: RequestRedemptionArgs,
+    ) -> Result<()> {
+        let owner = self.owner.to_account_info();
+        let mint = self.mint.to_account_info();
+
+        let token_metadata = utils::get_metadata(&mint)?;
+
+        utils::validate_collection(
+            &mint,
+            &token_metadata.additional_metadata,
+            self.collection.key(),
+        )?;
+
+        let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+        let metal = utils::get_metal(&token_metadata.additional_metadata)?;
+
+        self.redemption_request.open(
+            owner.key(),
+            mint.key(),
+            args.discriminant,
+            metal,
+            &weight,
+            args.delivery_hash,
+            bumps.redemption_request,
+        )?;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    authority: owner.clone(),
+                    from: self.owner_token_account.to_account_info(),
+                    mint: mint.clone(),
+                    to: self.redemption_token_account.to_account_info(),
+                },
+            ),
+            1,
+            0,
+        )?;
+
+        emit!(RequestRedemptionEvent {
+            redemption_request: self.redemption_request.key(),
+            owner: owner.key(),
+            mint: mint.key(),
+            metal,
+            weight: weight.milligrams,
+            fineness: weight.fineness,
+            delivery_hash: args.delivery_hash,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RequestRedemptionArgs {
+    pub discriminant: u64,
+    /// Hash of the delivery details shared with the operator off-chain
+    pub delivery_hash: [u8; 32],
+}
+
+#[event]
+pub struct RequestRedemptionEvent {
+    pub redemption_request: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub metal: Metal,
+    pub weight: u64,
+    pub fineness: u16,
+    pub delivery_hash: [u8; 32],
+}
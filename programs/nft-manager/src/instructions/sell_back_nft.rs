@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        ASSET_CLASS_TAG, BAR_RECORD_TAG, BUYBACK_TAG, COLLECTION_TAG, MINT_FEES_COLLECTOR_TAG,
+        MINT_TAG, NFT_MANAGER_TAG, ORACLE_CONFIG_TAG, RESERVE_LEDGER_TAG,
+    },
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        bar_record::BarRecord,
+        buyback::Buyback,
+        mint_fees_collector::MintFeesCollector,
+        nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+        reserve_ledger::ReserveLedger,
+    },
+    utils,
+};
+
+#[derive(Accounts)]
+#[instruction(args: SellBackNFTArgs)]
+pub struct SellBackNFT<'info> {
+    /// CHECK: Validated against the oracle config when the price is read
+    pub metal_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub metal_secondary_price: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated against the oracle config when the price is read
+    pub sol_price_update: UncheckedAccount<'info>,
+    /// CHECK: Optional secondary source, validated against the oracle config when the price is read
+    pub sol_secondary_price: Option<UncheckedAccount<'info>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &args.discriminant.to_le_bytes()],
+        bump,
+        constraint = mint.supply > 0 @NFTManagerError::InvalidMintSupply,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+        constraint = token_account.amount == mint.supply @NFTManagerError::InvalidTokenAccount,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// Receives the NFT when the buyback keeps an inventory, left out otherwise
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = buyback,
+        associated_token::token_program = token_program,
+    )]
+    pub inventory_token_account:
+        Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+
+    #[account(
+        seeds = [COLLECTION_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    /// CHECK: This account is validated via the seeds
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BUYBACK_TAG, nft_manager.collection_id.as_bytes()],
+        bump = buyback.bump,
+    )]
+    pub buyback: Box<Account<'info, Buyback>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
+        bump,
+    )]
+    pub mint_fees_collector: Box<Account<'info, MintFeesCollector>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [ASSET_CLASS_TAG, asset_class.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Box<Account<'info, AssetClass>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_LEDGER_TAG],
+        bump = reserve_ledger.bump,
+    )]
+    pub reserve_ledger: Box<Account<'info, ReserveLedger>>,
+
+    /// Required when the NFT carries a bar serial and gets burned
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, bar_record.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Option<Box<Account<'info, BarRecord>>>,
+}
+
+impl<'info> SellBackNFT<'info> {
+    /// Pays the seller the spot value of the NFT minus the buyback spread out of the mint fees
+    /// collector, then burns the NFT or moves it to the buyback inventory.
+    pub fn sell_back_nft(&mut self, bumps: &SellBackNFTBumps, args: SellBackNFTArgs) -> Result<()> {
+        let token_program = self.token_program.to_account_info();
+        let mint = self.mint.to_account_info();
+        let signer = self.signer.to_account_info();
+        let source = self.token_account.to_account_info();
+        let amount = self.mint.supply;
+
+        let token_metadata = utils::get_metadata(&mint)?;
+        utils::validate_collection(
+            &mint,
+            &token_metadata.additional_metadata,
+            self.collection.key(),
+        )?;
+        utils::validate_metal(&token_metadata.additional_metadata, self.asset_class.metal)?;
+        let weight = utils::validate_weight(&token_metadata.additional_metadata)?;
+
+        let value = utils::get_metal_value_in_lamport(
+            utils::PriceSource {
+                primary: &self.metal_price_update,
+                secondary: self.metal_secondary_price.as_deref(),
+            },
+            utils::PriceSource {
+                primary: &self.sol_price_update,
+                secondary: self.sol_secondary_price.as_deref(),
+            },
+            &self.oracle_config,
+            &self.asset_class,
+            &weight,
+        )?;
+
+        let payout = self.buyback.calc_payout(value)?;
+
+        require_gte!(payout, args.min_lamports, NFTManagerError::SlippageExceeded);
+
+        self.buyback
+            .record_payout(payout, Clock::get()?.unix_timestamp)?;
+
+        let rent_exempt_balance =
+            Rent::get()?.minimum_balance(self.mint_fees_collector.to_account_info().data_len());
+        let available = self
+            .mint_fees_collector
+            .get_lamports()
+            .saturating_sub(rent_exempt_balance);
+
+        require_gte!(available, payout, NFTManagerError::InsufficientFunds);
+
+        self.mint_fees_collector.sub_lamports(payout)?;
+        self.signer.add_lamports(payout)?;
+
+        let kept = self.buyback.keep_in_inventory;
+
+        if kept {
+            let inventory_token_account = self
+                .inventory_token_account
+                .as_ref()
+                .ok_or(NFTManagerError::InvalidTokenAccount)?;
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    token_program,
+                    token_interface::TransferChecked {
+                        from: source,
+                        mint: mint.clone(),
+                        to: inventory_token_account.to_account_info(),
+                        authority: signer.clone(),
+                    },
+                ),
+                amount,
+                0,
+            )?;
+        } else {
+            if let Some(serial) = utils::get_bar_serial(&token_metadata.additional_metadata) {
+                let bar_record = self
+                    .bar_record
+                    .as_mut()
+                    .ok_or(NFTManagerError::BarMismatch)?;
+                require!(bar_record.serial == serial, NFTManagerError::BarMismatch);
+
+                bar_record.release(&weight)?;
+            }
+
+            self.reserve_ledger.record_burn(
+                self.nft_manager.collection,
+                self.asset_class.metal,
+                weight.milligrams,
+            )?;
+
+            let collection_id = self.nft_manager.collection_id.clone();
+            let mint_seeds = &[
+                MINT_TAG,
+                collection_id.as_bytes(),
+                &args.discriminant.to_le_bytes(),
+                &[bumps.mint],
+            ];
+            let mint_signer_seeds = &[&mint_seeds[..]];
+
+            token_interface::burn(
+                CpiContext::new(
+                    token_program.clone(),
+                    token_interface::Burn {
+                        mint: mint.clone(),
+                        from: source,
+                        authority: signer.clone(),
+                    },
+                ),
+                amount,
+            )?;
+
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program,
+                token_interface::CloseAccount {
+                    account: mint.clone(),
+                    destination: signer.clone(),
+                    authority: mint.clone(),
+                },
+                mint_signer_seeds,
+            ))?;
+        }
+
+        emit!(SellBackNFTEvent {
+            seller: signer.key(),
+            mint: mint.key(),
+            discriminant: args.discriminant,
+            metal: self.asset_class.metal,
+            weight: weight.milligrams,
+            fineness: weight.fineness,
+            value,
+            payout,
+            kept,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SellBackNFTArgs {
+    pub discriminant: u64,
+    /// Lowest payout the seller accepts
+    pub min_lamports: u64,
+}
+
+#[event]
+pub struct SellBackNFTEvent {
+    pub seller: Pubkey,
+    pub mint: Pubkey,
+    pub discriminant: u64,
+    pub metal: Metal,
+    pub weight: u64,
+    pub fineness: u16,
+    /// Spot value before the spread
+    pub value: u64,
+    pub payout: u64,
+    /// Whether the NFT went to the inventory instead of being burned
+    pub kept: bool,
+}
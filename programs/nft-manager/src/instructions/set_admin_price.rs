@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ADMIN_PRICE_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{admin_price::AdminPrice, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+#[instruction(args: SetAdminPriceArgs)]
+pub struct SetAdminPrice<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + AdminPrice::INIT_SPACE,
+        seeds = [ADMIN_PRICE_TAG, args.feed_id.as_ref()],
+        bump
+    )]
+    pub admin_price: Account<'info, AdminPrice>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Account<'info, NFTManager>,
+}
+
+impl<'info> SetAdminPrice<'info> {
+    pub fn set_admin_price(
+        &mut self,
+        bumps: &SetAdminPriceBumps,
+        args: SetAdminPriceArgs,
+    ) -> Result<()> {
+        require_gt!(args.price, 0, NFTManagerError::NegativePrice);
+
+        self.admin_price.init(args.feed_id, bumps.admin_price)?;
+        self.admin_price
+            .set_price(args.price, args.conf, args.exponent)?;
+
+        emit!(SetAdminPriceEvent {
+            admin_price: self.admin_price.key(),
+            feed_id: args.feed_id,
+            price: args.price,
+            conf: args.conf,
+            exponent: args.exponent,
+            publish_time: self.admin_price.publish_time,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetAdminPriceArgs {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+}
+
+#[event]
+pub struct SetAdminPriceEvent {
+    pub admin_price: Pubkey,
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BUYBACK_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{buyback::Buyback, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+pub struct SetBuybackConfig<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + Buyback::INIT_SPACE,
+        seeds = [BUYBACK_TAG, nft_manager.collection_id.as_bytes()],
+        bump
+    )]
+    pub buyback: Box<Account<'info, Buyback>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> SetBuybackConfig<'info> {
+    /// Updates the terms only, the payouts of the current day keep counting against the new limit.
+    pub fn set_buyback_config(
+        &mut self,
+        bumps: &SetBuybackConfigBumps,
+        args: SetBuybackConfigArgs,
+    ) -> Result<()> {
+        self.buyback.set_config(
+            args.spread_bps,
+            args.daily_limit,
+            args.keep_in_inventory,
+            bumps.buyback,
+        )?;
+
+        emit!(SetBuybackConfigEvent {
+            buyback: self.buyback.key(),
+            spread_bps: args.spread_bps,
+            daily_limit: args.daily_limit,
+            keep_in_inventory: args.keep_in_inventory,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetBuybackConfigArgs {
+    pub spread_bps: u16,
+    pub daily_limit: u64,
+    pub keep_in_inventory: bool,
+}
+
+#[event]
+pub struct SetBuybackConfigEvent {
+    pub buyback: Pubkey,
+    pub spread_bps: u16,
+    pub daily_limit: u64,
+    pub keep_in_inventory: bool,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ASSET_CLASS_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{
+        asset_class::{AssetClass, Metal},
+        nft_manager::NFTManager,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args: UpdateAssetClassArgs)]
+pub struct UpdateAssetClass<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ASSET_CLASS_TAG, args.metal.seed()],
+        bump = asset_class.bump,
+    )]
+    pub asset_class: Account<'info, AssetClass>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Account<'info, NFTManager>,
+}
+
+impl<'info> UpdateAssetClass<'info> {
+    pub fn update_asset_class(&mut self, args: UpdateAssetClassArgs) -> Result<()> {
+        match args.field {
+            AssetClassField::FeedId(feed_id) => {
+                self.asset_class.feed_id = feed_id;
+            }
+            AssetClassField::PriceUnitMicrograms(price_unit_micrograms) => {
+                self.asset_class
+                    .set_price_unit_micrograms(price_unit_micrograms)?;
+            }
+            AssetClassField::Enabled(enabled) => {
+                self.asset_class.enabled = enabled;
+            }
+            AssetClassField::SecondaryFeedId(feed_id) => {
+                self.asset_class.secondary_feed_id = feed_id;
+            }
+            AssetClassField::WeightLimits {
+                min_weight,
+                max_weight,
+            } => {
+                self.asset_class.set_weight_limits(min_weight, max_weight)?;
+            }
+            AssetClassField::Denominations(denominations) => {
+                self.asset_class.set_denominations(denominations)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateAssetClassArgs {
+    pub metal: Metal,
+    pub field: AssetClassField,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub enum AssetClassField {
+    FeedId([u8; 32]),
+    PriceUnitMicrograms(u64),
+    Enabled(bool),
+    SecondaryFeedId(Option<[u8; 32]>),
+    WeightLimits { min_weight: u64, max_weight: u64 },
+    Denominations(Vec<u64>),
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ATTESTATION_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{asset_class::Metal, attestation::Attestation, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+#[instruction(args: UpdateAttestationConfigArgs)]
+pub struct UpdateAttestationConfig<'info> {
+    #[account(address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ATTESTATION_TAG, args.metal.seed()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Box<Account<'info, Attestation>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> UpdateAttestationConfig<'info> {
+    pub fn update_attestation_config(&mut self, args: UpdateAttestationConfigArgs) -> Result<()> {
+        self.attestation.set_config(args.auditor, args.max_age)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateAttestationConfigArgs {
+    pub metal: Metal,
+    pub auditor: Pubkey,
+    pub max_age: u64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BAR_RECORD_TAG, BAR_REGISTRY_TAG},
+    errors::NFTManagerError,
+    states::{
+        bar_record::{BarRecord, BarStatus},
+        bar_registry::BarRegistry,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args: UpdateBarArgs)]
+pub struct UpdateBar<'info> {
+    #[account(address = bar_registry.operator @NFTManagerError::OnlyOperatorAllowed)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [BAR_REGISTRY_TAG],
+        bump = bar_registry.bump,
+    )]
+    pub bar_registry: Box<Account<'info, BarRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [BAR_RECORD_TAG, args.serial.as_bytes()],
+        bump = bar_record.bump,
+    )]
+    pub bar_record: Box<Account<'info, BarRecord>>,
+}
+
+impl<'info> UpdateBar<'info> {
+    pub fn update_bar(&mut self, args: UpdateBarArgs) -> Result<()> {
+        match args.field {
+            BarField::VaultLocation(vault_location) => {
+                self.bar_record.set_vault_location(vault_location)?;
+            }
+            BarField::Status(status) => {
+                self.bar_record.set_status(status)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateBarArgs {
+    pub serial: String,
+    pub field: BarField,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub enum BarField {
+    VaultLocation(String),
+    Status(BarStatus),
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BAR_REGISTRY_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{bar_registry::BarRegistry, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+pub struct UpdateBarOperator<'info> {
+    #[account(address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BAR_REGISTRY_TAG],
+        bump = bar_registry.bump,
+    )]
+    pub bar_registry: Box<Account<'info, BarRegistry>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> UpdateBarOperator<'info> {
+    pub fn update_bar_operator(&mut self, operator: Pubkey) -> Result<()> {
+        self.bar_registry.operator = operator;
+
+        Ok(())
+    }
+}
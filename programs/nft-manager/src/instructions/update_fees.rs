@@ -16,14 +16,14 @@ pub struct UpdateFees<'info> {
     pub signer: Signer<'info>,
     #[account(
         mut,
-        seeds = [FEES_COLLECTOR_TAG],
+        seeds = [FEES_COLLECTOR_TAG, nft_manager.collection_id.as_bytes()],
         bump,
     )]
     pub fees_collector: Account<'info, FeesCollector>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Account<'info, NFTManager>,
 }
@@ -32,10 +32,35 @@ impl<'info> UpdateFees<'info> {
     pub fn update_fees(&mut self, args: UpdateFeesArgs) -> Result<()> {
         match args.fee {
             Fees::FractionalizeFee => {
-                self.fees_collector.fractionalize_fee = args.new_fee;
+                self.fees_collector.fractionalize_fee = args
+                    .new_fee
+                    .try_into()
+                    .map_err(|_| NFTManagerError::InvalidFee)?;
             }
             Fees::SellFee => {
-                self.fees_collector.sell_fee = args.new_fee;
+                self.fees_collector.sell_fee = args
+                    .new_fee
+                    .try_into()
+                    .map_err(|_| NFTManagerError::InvalidFee)?;
+            }
+            Fees::MintPremiumBps => {
+                self.fees_collector.mint_premium_bps = args
+                    .new_fee
+                    .try_into()
+                    .map_err(|_| NFTManagerError::InvalidFee)?;
+            }
+            Fees::FabricationFee => {
+                self.fees_collector.fabrication_fee = args.new_fee;
+            }
+            Fees::MergeFee => {
+                self.fees_collector.merge_fee = args.new_fee;
+            }
+            Fees::CancelRefundBps => {
+                self.fees_collector.set_cancel_refund_bps(
+                    args.new_fee
+                        .try_into()
+                        .map_err(|_| NFTManagerError::InvalidFee)?,
+                )?;
             }
         }
 
@@ -46,11 +71,15 @@ impl<'info> UpdateFees<'info> {
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateFeesArgs {
     pub fee: Fees,
-    pub new_fee: u32,
+    pub new_fee: u64,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub enum Fees {
     FractionalizeFee,
     SellFee,
+    MintPremiumBps,
+    FabricationFee,
+    MergeFee,
+    CancelRefundBps,
 }
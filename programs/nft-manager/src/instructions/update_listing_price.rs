@@ -20,7 +20,7 @@ pub struct UpdateListingPrice<'info> {
     pub owner: Signer<'info>,
 
     #[account(
-        seeds = [MINT_TAG, &args.discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &args.discriminant.to_le_bytes()],
         bump,
     )]
     pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
@@ -34,8 +34,8 @@ pub struct UpdateListingPrice<'info> {
     pub listing: Box<Account<'info, Listing>>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Box<Account<'info, NFTManager>>,
 }
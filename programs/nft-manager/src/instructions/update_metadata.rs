@@ -31,14 +31,14 @@ pub struct UpdateMetadata<'info> {
 
     #[account(
         mut,
-        seeds = [MINT_TAG, &args.discriminant.to_le_bytes()],
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &args.discriminant.to_le_bytes()],
         bump,
     )]
     pub mint: InterfaceAccount<'info, token_interface::Mint>,
 
     #[account(
-        seeds = [NFT_MANAGER_TAG],
-        bump,
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
     )]
     pub nft_manager: Account<'info, NFTManager>,
 }
@@ -89,7 +89,13 @@ impl<'info> UpdateMetadata<'info> {
             )?;
         }
 
-        let mint_seeds = &[MINT_TAG, &args.discriminant.to_le_bytes(), &[bumps.mint]];
+        let collection_id = self.nft_manager.collection_id.clone();
+        let mint_seeds = &[
+            MINT_TAG,
+            collection_id.as_bytes(),
+            &args.discriminant.to_le_bytes(),
+            &[bumps.mint],
+        ];
         let mint_signer_seeds = &[&mint_seeds[..]];
 
         token_interface::token_metadata_update_field(
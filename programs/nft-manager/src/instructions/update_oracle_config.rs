@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{NFT_MANAGER_TAG, ORACLE_CONFIG_TAG},
+    errors::NFTManagerError,
+    states::{nft_manager::NFTManager, oracle_config::OracleConfig},
+};
+
+#[derive(Accounts)]
+pub struct UpdateOracleConfig<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORACLE_CONFIG_TAG],
+        bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Account<'info, NFTManager>,
+}
+
+impl<'info> UpdateOracleConfig<'info> {
+    pub fn update_oracle_config(&mut self, args: UpdateOracleConfigArgs) -> Result<()> {
+        match args {
+            UpdateOracleConfigArgs::SolFeedId(feed_id) => {
+                self.oracle_config.sol_feed_id = feed_id;
+            }
+            UpdateOracleConfigArgs::MaxAge(max_age) => {
+                self.oracle_config.set_max_age(max_age)?;
+            }
+            UpdateOracleConfigArgs::MaxConfBps(max_conf_bps) => {
+                self.oracle_config.set_max_conf_bps(max_conf_bps)?;
+            }
+            UpdateOracleConfigArgs::PriceUpdateOwners(owners) => {
+                self.oracle_config.set_price_update_owners(owners)?;
+            }
+            UpdateOracleConfigArgs::SecondarySolFeedId(feed_id) => {
+                self.oracle_config.secondary_sol_feed_id = feed_id;
+            }
+            UpdateOracleConfigArgs::MaxDeviationBps(max_deviation_bps) => {
+                self.oracle_config
+                    .set_max_deviation_bps(max_deviation_bps)?;
+            }
+            UpdateOracleConfigArgs::AllowPrimaryFallback(allow) => {
+                self.oracle_config.allow_primary_fallback = allow;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub enum UpdateOracleConfigArgs {
+    SolFeedId([u8; 32]),
+    MaxAge(u64),
+    MaxConfBps(u16),
+    PriceUpdateOwners(Vec<Pubkey>),
+    SecondarySolFeedId(Option<[u8; 32]>),
+    MaxDeviationBps(u16),
+    AllowPrimaryFallback(bool),
+}
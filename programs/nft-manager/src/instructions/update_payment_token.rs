@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{NFT_MANAGER_TAG, PAYMENT_TOKEN_TAG},
+    errors::NFTManagerError,
+    states::{nft_manager::NFTManager, payment_token::PaymentToken},
+};
+
+#[derive(Accounts)]
+pub struct UpdatePaymentToken<'info> {
+    #[account(
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PAYMENT_TOKEN_TAG, payment_token.mint.as_ref()],
+        bump = payment_token.bump,
+    )]
+    pub payment_token: Account<'info, PaymentToken>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG],
+        bump,
+    )]
+    pub nft_manager: Account<'info, NFTManager>,
+}
+
+impl<'info> UpdatePaymentToken<'info> {
+    pub fn update_payment_token(&mut self, enabled: bool) -> Result<()> {
+        self.payment_token.enabled = enabled;
+
+        Ok(())
+    }
+}
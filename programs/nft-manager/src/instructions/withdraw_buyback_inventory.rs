@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{BUYBACK_TAG, MINT_TAG, NFT_MANAGER_TAG},
+    errors::NFTManagerError,
+    states::{buyback::Buyback, nft_manager::NFTManager},
+};
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct WithdrawBuybackInventory<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        mut,
+        address = nft_manager.authority @NFTManagerError::OnlyAdminAllowed
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = buyback,
+        associated_token::token_program = token_program,
+    )]
+    pub inventory_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [BUYBACK_TAG, nft_manager.collection_id.as_bytes()],
+        bump = buyback.bump,
+    )]
+    pub buyback: Box<Account<'info, Buyback>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> WithdrawBuybackInventory<'info> {
+    /// Moves a bought back NFT to the admin so it can be listed again.
+    pub fn withdraw_buyback_inventory(&mut self) -> Result<()> {
+        let token_program = self.token_program.to_account_info();
+        let buyback = self.buyback.to_account_info();
+        let inventory_token_account = self.inventory_token_account.to_account_info();
+
+        let collection_id = self.nft_manager.collection_id.clone();
+        let buyback_seeds = &[BUYBACK_TAG, collection_id.as_bytes(), &[self.buyback.bump]];
+        let buyback_signer_seeds = &[&buyback_seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::TransferChecked {
+                    from: inventory_token_account.clone(),
+                    mint: self.mint.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                    authority: buyback.clone(),
+                },
+                buyback_signer_seeds,
+            ),
+            self.inventory_token_account.amount,
+            0,
+        )?;
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program,
+            token_interface::CloseAccount {
+                account: inventory_token_account,
+                destination: self.signer.to_account_info(),
+                authority: buyback,
+            },
+            buyback_signer_seeds,
+        ))?;
+
+        emit!(WithdrawBuybackInventoryEvent {
+            mint: self.mint.key(),
+            recipient: self.signer.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct WithdrawBuybackInventoryEvent {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022, token_interface};
+
+use crate::{
+    constants::{
+        GRAM_VAULT_TAG, MINT_TAG, NFT_MANAGER_TAG, VAULTED_NFT_TAG, VAULTED_NFT_TOKEN_ACCOUNT_TAG,
+    },
+    errors::NFTManagerError,
+    states::{gram_vault::GramVault, nft_manager::NFTManager, vaulted_nft::VaultedNFT},
+};
+
+#[derive(Accounts)]
+#[instruction(discriminant: u64)]
+pub struct WithdrawFromGramVault<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Checked against the vaulted NFT, receives the rent back
+    pub depositor: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [MINT_TAG, nft_manager.collection_id.as_bytes(), &discriminant.to_le_bytes()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [GRAM_VAULT_TAG, gram_vault.metal.seed(), &gram_vault.fineness.to_le_bytes()],
+        bump = gram_vault.bump,
+    )]
+    pub gram_vault: Box<Account<'info, GramVault>>,
+
+    #[account(
+        mut,
+        address = gram_vault.mint,
+    )]
+    pub gram_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = gram_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub signer_gram_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [VAULTED_NFT_TAG, mint.key().as_ref()],
+        bump = vaulted_nft.bump,
+        has_one = depositor @NFTManagerError::InvalidTokenAccount,
+        has_one = gram_vault @NFTManagerError::InvalidTokenAccount,
+    )]
+    pub vaulted_nft: Box<Account<'info, VaultedNFT>>,
+
+    #[account(
+        mut,
+        seeds = [VAULTED_NFT_TOKEN_ACCOUNT_TAG, mint.key().as_ref()],
+        bump = vaulted_nft.token_account_bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [NFT_MANAGER_TAG, nft_manager.collection_id.as_bytes()],
+        bump = nft_manager.bump,
+    )]
+    pub nft_manager: Box<Account<'info, NFTManager>>,
+}
+
+impl<'info> WithdrawFromGramVault<'info> {
+    pub fn withdraw_from_gram_vault(&mut self) -> Result<()> {
+        let signer = self.signer.to_account_info();
+        let token_program = self.token_program.to_account_info();
+        let gram_vault = self.gram_vault.to_account_info();
+        let weight = self.vaulted_nft.weight;
+
+        token_interface::burn(
+            CpiContext::new(
+                token_program.clone(),
+                token_interface::Burn {
+                    mint: self.gram_mint.to_account_info(),
+                    from: self.signer_gram_token_account.to_account_info(),
+                    authority: signer.clone(),
+                },
+            ),
+            weight,
+        )?;
+
+        let gram_vault_seeds = &[
+            GRAM_VAULT_TAG,
+            self.gram_vault.metal.seed(),
+            &self.gram_vault.fineness.to_le_bytes(),
+            &[self.gram_vault.bump],
+        ];
+        let gram_vault_signer_seeds = &[&gram_vault_seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::TransferChecked {
+                    authority: gram_vault.clone(),
+                    from: self.vault_token_account.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                },
+                gram_vault_signer_seeds,
+            ),
+            1,
+            0,
+        )?;
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program,
+            token_interface::CloseAccount {
+                account: self.vault_token_account.to_account_info(),
+                destination: self.depositor.to_account_info(),
+                authority: gram_vault,
+            },
+            gram_vault_signer_seeds,
+        ))?;
+
+        self.gram_vault.withdraw(weight)?;
+
+        emit!(WithdrawFromGramVaultEvent {
+            gram_vault: self.gram_vault.key(),
+            mint: self.mint.key(),
+            owner: signer.key(),
+            weight,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct WithdrawFromGramVaultEvent {
+    pub gram_vault: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub weight: u64,
+}
@@ -31,21 +31,164 @@ pub mod nft_manager {
         Ok(())
     }
 
+    pub fn initialize_collection_manager(
+        ctx: Context<InitializeCollectionManager>,
+        args: InitializeCollectionManagerArgs,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_collection_manager(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn initialize_reserve_ledger(
+        ctx: Context<InitializeReserveLedger>,
+        args: InitializeReserveLedgerArgs,
+    ) -> Result<()> {
+        ctx.accounts.initialize_reserve_ledger(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn initialize_oracle_config(
+        ctx: Context<InitializeOracleConfig>,
+        args: InitializeOracleConfigArgs,
+    ) -> Result<()> {
+        ctx.accounts.initialize_oracle_config(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn initialize_finalize_index(ctx: Context<InitializeFinalizeIndex>) -> Result<()> {
+        ctx.accounts.initialize_finalize_index(&ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn initialize_attestation(
+        ctx: Context<InitializeAttestation>,
+        args: InitializeAttestationArgs,
+    ) -> Result<()> {
+        ctx.accounts.initialize_attestation(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn update_attestation_config(
+        ctx: Context<UpdateAttestationConfig>,
+        args: UpdateAttestationConfigArgs,
+    ) -> Result<()> {
+        ctx.accounts.update_attestation_config(args)?;
+        Ok(())
+    }
+
+    pub fn post_attestation(
+        ctx: Context<PostAttestation>,
+        args: PostAttestationArgs,
+    ) -> Result<()> {
+        ctx.accounts.post_attestation(args)?;
+        Ok(())
+    }
+
+    pub fn initialize_bar_registry(
+        ctx: Context<InitializeBarRegistry>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.initialize_bar_registry(&ctx.bumps, operator)?;
+        Ok(())
+    }
+
+    pub fn update_bar_operator(ctx: Context<UpdateBarOperator>, operator: Pubkey) -> Result<()> {
+        ctx.accounts.update_bar_operator(operator)?;
+        Ok(())
+    }
+
+    pub fn register_bar(ctx: Context<RegisterBar>, args: RegisterBarArgs) -> Result<()> {
+        ctx.accounts.register_bar(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn update_bar(ctx: Context<UpdateBar>, args: UpdateBarArgs) -> Result<()> {
+        ctx.accounts.update_bar(args)?;
+        Ok(())
+    }
+
+    pub fn migrate_nft_manager(ctx: Context<MigrateNFTManager>) -> Result<()> {
+        ctx.accounts.migrate_nft_manager()?;
+        Ok(())
+    }
+
     pub fn update_fees(ctx: Context<UpdateFees>, args: UpdateFeesArgs) -> Result<()> {
         ctx.accounts.update_fees(args)?;
         Ok(())
     }
 
+    pub fn update_oracle_config(
+        ctx: Context<UpdateOracleConfig>,
+        args: UpdateOracleConfigArgs,
+    ) -> Result<()> {
+        ctx.accounts.update_oracle_config(args)?;
+        Ok(())
+    }
+
+    pub fn create_asset_class(
+        ctx: Context<CreateAssetClass>,
+        args: CreateAssetClassArgs,
+    ) -> Result<()> {
+        ctx.accounts.create_asset_class(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn update_asset_class(
+        ctx: Context<UpdateAssetClass>,
+        args: UpdateAssetClassArgs,
+    ) -> Result<()> {
+        ctx.accounts.update_asset_class(args)?;
+        Ok(())
+    }
+
+    pub fn set_admin_price(ctx: Context<SetAdminPrice>, args: SetAdminPriceArgs) -> Result<()> {
+        ctx.accounts.set_admin_price(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn create_payment_token(ctx: Context<CreatePaymentToken>, enabled: bool) -> Result<()> {
+        ctx.accounts.create_payment_token(&ctx.bumps, enabled)?;
+        Ok(())
+    }
+
+    pub fn update_payment_token(ctx: Context<UpdatePaymentToken>, enabled: bool) -> Result<()> {
+        ctx.accounts.update_payment_token(enabled)?;
+        Ok(())
+    }
+
     pub fn mint_nft(ctx: Context<MintNFT>, args: MintNFTArgs) -> Result<()> {
         ctx.accounts.mint_nft(&ctx.bumps, args)?;
         Ok(())
     }
 
+    pub fn mint_nft_v2(ctx: Context<MintNFTV2>, args: MintNFTArgs) -> Result<()> {
+        ctx.accounts.mint_nft_v2(&ctx.bumps, args)?;
+        Ok(())
+    }
+
     pub fn finalize_mint_nft(ctx: Context<FinalizeMintNFT>, discriminant: u64) -> Result<()> {
         ctx.accounts.finalize_mint_nft(&ctx.bumps, discriminant)?;
         Ok(())
     }
 
+    pub fn mint_nft_with_token(
+        ctx: Context<MintNFTWithToken>,
+        args: MintNFTWithTokenArgs,
+    ) -> Result<()> {
+        ctx.accounts.mint_nft_with_token(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn batch_mint_nft<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchMintNFT<'info>>,
+        args: BatchMintNFTArgs,
+    ) -> Result<()> {
+        ctx.accounts
+            .batch_mint_nft(&ctx.bumps, ctx.remaining_accounts, args)?;
+        Ok(())
+    }
+
     pub fn fractionalize_nft(
         ctx: Context<FractionalizeNFT>,
         args: FractionalizeNFTArgs,
@@ -62,11 +205,114 @@ pub mod nft_manager {
         Ok(())
     }
 
+    pub fn finalize_fractionalize_nft_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeFractionalizeNFTBatch<'info>>,
+        _discriminant: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .finalize_fractionalize_nft_batch(&ctx.bumps, ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn cancel_fractionalize(
+        ctx: Context<CancelFractionalize>,
+        discriminant: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .cancel_fractionalize(&ctx.bumps, discriminant)?;
+        Ok(())
+    }
+
+    pub fn crank_finalize_mint(ctx: Context<CrankFinalizeMint>, discriminant: u64) -> Result<()> {
+        ctx.accounts.crank_finalize_mint(&ctx.bumps, discriminant)?;
+        Ok(())
+    }
+
+    pub fn crank_finalize_fractionalize(
+        ctx: Context<CrankFinalizeFractionalize>,
+        _discriminant: u64,
+    ) -> Result<()> {
+        ctx.accounts.crank_finalize_fractionalize(&ctx.bumps)?;
+        Ok(())
+    }
+
     pub fn burn_nft(ctx: Context<BurnNFT>, dicriminant: u64) -> Result<()> {
         ctx.accounts.burn_nft(&ctx.bumps, dicriminant)?;
         Ok(())
     }
 
+    pub fn request_redemption(
+        ctx: Context<RequestRedemption>,
+        args: RequestRedemptionArgs,
+    ) -> Result<()> {
+        ctx.accounts.request_redemption(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn fulfill_redemption(ctx: Context<FulfillRedemption>, discriminant: u64) -> Result<()> {
+        ctx.accounts.fulfill_redemption(&ctx.bumps, discriminant)?;
+        Ok(())
+    }
+
+    pub fn reject_redemption(ctx: Context<RejectRedemption>, _discriminant: u64) -> Result<()> {
+        ctx.accounts.reject_redemption(&ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn set_buyback_config(
+        ctx: Context<SetBuybackConfig>,
+        args: SetBuybackConfigArgs,
+    ) -> Result<()> {
+        ctx.accounts.set_buyback_config(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn sell_back_nft(ctx: Context<SellBackNFT>, args: SellBackNFTArgs) -> Result<()> {
+        ctx.accounts.sell_back_nft(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn withdraw_buyback_inventory(
+        ctx: Context<WithdrawBuybackInventory>,
+        _discriminant: u64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_buyback_inventory()?;
+        Ok(())
+    }
+
+    pub fn merge_nfts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MergeNFTs<'info>>,
+        args: MergeNFTsArgs,
+    ) -> Result<()> {
+        ctx.accounts
+            .merge_nfts(&ctx.bumps, ctx.remaining_accounts, args)?;
+        Ok(())
+    }
+
+    pub fn create_gram_vault(
+        ctx: Context<CreateGramVault>,
+        args: CreateGramVaultArgs,
+    ) -> Result<()> {
+        ctx.accounts.create_gram_vault(&ctx.bumps, args)?;
+        Ok(())
+    }
+
+    pub fn deposit_to_gram_vault(
+        ctx: Context<DepositToGramVault>,
+        _discriminant: u64,
+    ) -> Result<()> {
+        ctx.accounts.deposit_to_gram_vault(&ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn withdraw_from_gram_vault(
+        ctx: Context<WithdrawFromGramVault>,
+        _discriminant: u64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_from_gram_vault()?;
+        Ok(())
+    }
+
     pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
         ctx.accounts.update_metadata(&ctx.bumps, args)?;
 
@@ -99,6 +345,21 @@ pub mod nft_manager {
         Ok(())
     }
 
+    pub fn quote_mint(ctx: Context<QuoteMint>, args: QuoteMintArgs) -> Result<Quote> {
+        ctx.accounts.quote_mint(args)
+    }
+
+    pub fn quote_fractionalize(
+        ctx: Context<QuoteFractionalize>,
+        _discriminant: u64,
+    ) -> Result<Quote> {
+        ctx.accounts.quote_fractionalize()
+    }
+
+    pub fn quote_buy(ctx: Context<QuoteBuy>, _discriminant: u64, _seller: Pubkey) -> Result<Quote> {
+        ctx.accounts.quote_buy()
+    }
+
     pub fn admin_withdraw_fees(ctx: Context<AdminWithdrawFees>, amount: u64) -> Result<()> {
         ctx.accounts.withdraw_fees(amount)?;
         Ok(())
@@ -113,6 +374,15 @@ pub mod nft_manager {
         Ok(())
     }
 
+    pub fn admin_withdraw_payment_token(
+        ctx: Context<AdminWithdrawPaymentToken>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_payment_token(amount)?;
+
+        Ok(())
+    }
+
     pub fn create_user_account(ctx: Context<CreateUserAccount>) -> Result<()> {
         ctx.accounts.create_user_account(&ctx.bumps)?;
         Ok(())
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::Price;
+
+/// Price pushed by the admin, usable as a secondary source where no second oracle exists
+#[account]
+#[derive(InitSpace)]
+pub struct AdminPrice {
+    pub bump: u8,
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl AdminPrice {
+    pub fn init(&mut self, feed_id: [u8; 32], bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.feed_id = feed_id;
+
+        Ok(())
+    }
+
+    pub fn set_price(&mut self, price: i64, conf: u64, exponent: i32) -> Result<()> {
+        self.price = price;
+        self.conf = conf;
+        self.exponent = exponent;
+        self.publish_time = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    pub fn to_price(&self) -> Price {
+        Price {
+            price: self.price,
+            conf: self.conf,
+            exponent: self.exponent,
+            publish_time: self.publish_time,
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_DENOMINATIONS, errors::NFTManagerError};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Metal {
+    Gold,
+    Silver,
+    Platinum,
+    Palladium,
+}
+
+impl Metal {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Metal::Gold => "gold",
+            Metal::Silver => "silver",
+            Metal::Platinum => "platinum",
+            Metal::Palladium => "palladium",
+        }
+    }
+
+    pub fn seed(&self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl FromStr for Metal {
+    type Err = NFTManagerError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "gold" => Ok(Metal::Gold),
+            "silver" => Ok(Metal::Silver),
+            "platinum" => Ok(Metal::Platinum),
+            "palladium" => Ok(Metal::Palladium),
+            _ => Err(NFTManagerError::InvalidMetadata),
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AssetClass {
+    pub bump: u8,
+    pub metal: Metal,
+    pub feed_id: [u8; 32],
+    /// Micrograms of pure metal the oracle price is quoted for (31_103_477 for a troy ounce)
+    pub price_unit_micrograms: u64,
+    pub enabled: bool,
+    /// Feed checked against `feed_id` when set, either a Pyth feed or an `AdminPrice`
+    pub secondary_feed_id: Option<[u8; 32]>,
+    /// Smallest piece in milligrams, 0 to allow any
+    pub min_weight: u64,
+    /// Largest piece in milligrams, 0 to allow any
+    pub max_weight: u64,
+    /// Weights in milligrams pieces must be cut to, empty to allow any
+    #[max_len(MAX_DENOMINATIONS)]
+    pub denominations: Vec<u64>,
+}
+
+impl AssetClass {
+    pub fn init(
+        &mut self,
+        metal: Metal,
+        feed_id: [u8; 32],
+        price_unit_micrograms: u64,
+        enabled: bool,
+        bump: u8,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.metal = metal;
+        self.feed_id = feed_id;
+        self.enabled = enabled;
+        self.secondary_feed_id = None;
+        self.min_weight = 0;
+        self.max_weight = 0;
+        self.denominations = Vec::new();
+        self.set_price_unit_micrograms(price_unit_micrograms)?;
+
+        Ok(())
+    }
+
+    pub fn set_price_unit_micrograms(&mut self, price_unit_micrograms: u64) -> Result<()> {
+        require_gt!(price_unit_micrograms, 0, NFTManagerError::InvalidAssetClass);
+        self.price_unit_micrograms = price_unit_micrograms;
+
+        Ok(())
+    }
+
+    pub fn set_weight_limits(&mut self, min_weight: u64, max_weight: u64) -> Result<()> {
+        require!(
+            max_weight == 0 || min_weight <= max_weight,
+            NFTManagerError::InvalidAssetClass
+        );
+        self.min_weight = min_weight;
+        self.max_weight = max_weight;
+
+        Ok(())
+    }
+
+    pub fn set_denominations(&mut self, denominations: Vec<u64>) -> Result<()> {
+        require!(
+            denominations.len() <= MAX_DENOMINATIONS
+                && denominations.iter().all(|denomination| *denomination > 0),
+            NFTManagerError::InvalidAssetClass
+        );
+        self.denominations = denominations;
+
+        Ok(())
+    }
+
+    /// Checks a piece weight, in milligrams, against the limits and denominations.
+    pub fn validate_weight(&self, milligrams: u64) -> Result<()> {
+        require_gte!(
+            milligrams,
+            self.min_weight,
+            NFTManagerError::WeightBelowMinimum
+        );
+        require!(
+            self.max_weight == 0 || milligrams <= self.max_weight,
+            NFTManagerError::WeightAboveMaximum
+        );
+        require!(
+            self.denominations.is_empty() || self.denominations.contains(&milligrams),
+            NFTManagerError::WeightNotDenomination
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_class_validate_weight() {
+        let mut asset_class = AssetClass {
+            bump: 0,
+            metal: Metal::Gold,
+            feed_id: [0; 32],
+            price_unit_micrograms: 31_103_477,
+            enabled: true,
+            secondary_feed_id: None,
+            min_weight: 0,
+            max_weight: 0,
+            denominations: vec![],
+        };
+        assert!(asset_class.validate_weight(1).is_ok());
+
+        asset_class.set_weight_limits(1_000, 100_000).unwrap();
+        assert!(asset_class.validate_weight(999).is_err());
+        assert!(asset_class.validate_weight(100_001).is_err());
+        assert!(asset_class.set_weight_limits(10, 1).is_err());
+
+        asset_class.set_denominations(vec![1_000, 5_000]).unwrap();
+        assert!(asset_class.validate_weight(5_000).is_ok());
+        assert!(asset_class.validate_weight(2_000).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::NFTManagerError, states::asset_class::Metal};
+
+/// Latest vault report posted by the auditor for one metal. Minting that metal stops once the
+/// outstanding weight would exceed the attested weight or the report gets older than `max_age`.
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub bump: u8,
+    pub metal: Metal,
+    /// Only signer allowed to post reports, distinct from the manager authority
+    pub auditor: Pubkey,
+    /// Seconds a report can be relied on for minting
+    pub max_age: u64,
+    /// Gross weight in milligrams held in the vault
+    pub attested_weight: u64,
+    /// Hash of the signed audit report
+    pub report_hash: [u8; 32],
+    /// Time the vault was counted, 0 until the first report
+    pub timestamp: i64,
+}
+
+impl Attestation {
+    pub fn init(&mut self, metal: Metal, auditor: Pubkey, max_age: u64, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.metal = metal;
+        self.attested_weight = 0;
+        self.report_hash = [0; 32];
+        self.timestamp = 0;
+        self.set_config(auditor, max_age)?;
+
+        Ok(())
+    }
+
+    pub fn set_config(&mut self, auditor: Pubkey, max_age: u64) -> Result<()> {
+        require_gt!(max_age, 0, NFTManagerError::InvalidAttestation);
+        self.auditor = auditor;
+        self.max_age = max_age;
+
+        Ok(())
+    }
+
+    /// Reports must be newer than the current one and cannot be dated in the future.
+    pub fn post(
+        &mut self,
+        attested_weight: u64,
+        report_hash: [u8; 32],
+        timestamp: i64,
+    ) -> Result<()> {
+        require!(
+            timestamp > self.timestamp && timestamp <= Clock::get()?.unix_timestamp,
+            NFTManagerError::InvalidAttestation
+        );
+        self.attested_weight = attested_weight;
+        self.report_hash = report_hash;
+        self.timestamp = timestamp;
+
+        Ok(())
+    }
+
+    /// Checks the weight outstanding once a mint went through against the latest report.
+    pub fn validate_reserve(&self, outstanding_weight: u64) -> Result<()> {
+        let age = Clock::get()?
+            .unix_timestamp
+            .checked_sub(self.timestamp)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        require!(
+            self.timestamp > 0 && age as u64 <= self.max_age,
+            NFTManagerError::StaleAttestation
+        );
+        require_gte!(
+            self.attested_weight,
+            outstanding_weight,
+            NFTManagerError::ReserveExceeded
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_BAR_SERIAL_LEN, MAX_REFINER_LEN, MAX_VAULT_LOCATION_LEN},
+    errors::NFTManagerError,
+    states::asset_class::Metal,
+    utils::Weight,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum BarStatus {
+    /// In the vault and free to back a new NFT
+    Unassigned,
+    /// Backing one NFT, or the pieces cut from it
+    Assigned,
+    /// Out of the vault, it can no longer back an NFT
+    Retired,
+}
+
+/// A serial-numbered physical bar. A bar backs one NFT, whose pieces carry its serial when split
+/// or merged.
+#[account]
+#[derive(InitSpace)]
+pub struct BarRecord {
+    pub bump: u8,
+    #[max_len(MAX_BAR_SERIAL_LEN)]
+    pub serial: String,
+    #[max_len(MAX_REFINER_LEN)]
+    pub refiner: String,
+    #[max_len(MAX_VAULT_LOCATION_LEN)]
+    pub vault_location: String,
+    pub metal: Metal,
+    /// Gross weight in milligrams
+    pub gross_weight: u64,
+    /// Parts per ten thousand of pure metal
+    pub fineness: u16,
+    /// Weight of the pure metal in milligrams
+    pub fine_weight: u64,
+    pub status: BarStatus,
+    /// Weight in milligrams of the live NFTs carrying the serial
+    pub backed_weight: u64,
+}
+
+impl BarRecord {
+    pub fn init(
+        &mut self,
+        serial: String,
+        refiner: String,
+        vault_location: String,
+        metal: Metal,
+        weight: &Weight,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            !serial.is_empty() && serial.len() <= MAX_BAR_SERIAL_LEN,
+            NFTManagerError::InvalidBar
+        );
+        require!(
+            refiner.len() <= MAX_REFINER_LEN,
+            NFTManagerError::InvalidBar
+        );
+
+        self.bump = bump;
+        self.serial = serial;
+        self.refiner = refiner;
+        self.metal = metal;
+        self.gross_weight = weight.milligrams;
+        self.fineness = weight.fineness;
+        self.fine_weight = weight.fine_milligrams()?;
+        self.status = BarStatus::Unassigned;
+        self.backed_weight = 0;
+        self.set_vault_location(vault_location)?;
+
+        Ok(())
+    }
+
+    pub fn set_vault_location(&mut self, vault_location: String) -> Result<()> {
+        require!(
+            vault_location.len() <= MAX_VAULT_LOCATION_LEN,
+            NFTManagerError::InvalidBar
+        );
+        self.vault_location = vault_location;
+
+        Ok(())
+    }
+
+    /// Moves a bar in or out of the vault. Assigned bars only change through minting and burning.
+    pub fn set_status(&mut self, status: BarStatus) -> Result<()> {
+        require!(
+            self.status != BarStatus::Assigned && status != BarStatus::Assigned,
+            NFTManagerError::BarUnavailable
+        );
+        self.status = status;
+
+        Ok(())
+    }
+
+    /// Binds the bar to a newly minted NFT of the exact same metal and weight.
+    pub fn assign(&mut self, metal: Metal, weight: &Weight) -> Result<()> {
+        require!(
+            self.status == BarStatus::Unassigned,
+            NFTManagerError::BarUnavailable
+        );
+        require!(
+            self.metal == metal
+                && self.gross_weight == weight.milligrams
+                && self.fineness == weight.fineness,
+            NFTManagerError::BarMismatch
+        );
+
+        self.status = BarStatus::Assigned;
+        self.backed_weight = weight.milligrams;
+
+        Ok(())
+    }
+
+    /// Takes the bar out of the vault for the NFT it backs. The bar is delivered whole, so pieces
+    /// have to be merged back into the full weight before they are redeemed.
+    pub fn redeem(&mut self, weight: &Weight) -> Result<()> {
+        require!(
+            self.status == BarStatus::Assigned,
+            NFTManagerError::BarUnavailable
+        );
+        require!(
+            weight.milligrams == self.gross_weight && self.backed_weight == self.gross_weight,
+            NFTManagerError::BarSplit
+        );
+
+        self.status = BarStatus::Retired;
+        self.backed_weight = 0;
+
+        Ok(())
+    }
+
+    /// Drops a burned NFT or piece from the backing, freeing the bar once no piece is left.
+    pub fn release(&mut self, weight: &Weight) -> Result<()> {
+        require!(
+            self.status == BarStatus::Assigned,
+            NFTManagerError::BarUnavailable
+        );
+        self.backed_weight = self
+            .backed_weight
+            .checked_sub(weight.milligrams)
+            .ok_or(NFTManagerError::BarMismatch)?;
+
+        if self.backed_weight == 0 {
+            self.status = BarStatus::Unassigned;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEIGHT: Weight = Weight {
+        milligrams: 100_000,
+        fineness: 9999,
+    };
+
+    fn bar() -> BarRecord {
+        BarRecord {
+            bump: 255,
+            serial: "AB123".to_string(),
+            refiner: "Refiner".to_string(),
+            vault_location: "Vault".to_string(),
+            metal: Metal::Gold,
+            gross_weight: WEIGHT.milligrams,
+            fineness: WEIGHT.fineness,
+            fine_weight: WEIGHT.fine_milligrams().unwrap(),
+            status: BarStatus::Unassigned,
+            backed_weight: 0,
+        }
+    }
+
+    fn piece(milligrams: u64) -> Weight {
+        Weight {
+            milligrams,
+            fineness: WEIGHT.fineness,
+        }
+    }
+
+    #[test]
+    fn test_bar_backs_a_single_nft() {
+        let mut bar = bar();
+
+        assert!(bar.assign(Metal::Silver, &WEIGHT).is_err());
+        assert!(bar.assign(Metal::Gold, &piece(50_000)).is_err());
+        bar.assign(Metal::Gold, &WEIGHT).unwrap();
+        assert_eq!(bar.backed_weight, WEIGHT.milligrams);
+        assert_eq!(
+            bar.assign(Metal::Gold, &WEIGHT).unwrap_err(),
+            NFTManagerError::BarUnavailable.into()
+        );
+
+        bar.release(&WEIGHT).unwrap();
+        assert_eq!(bar.status, BarStatus::Unassigned);
+        assert_eq!(bar.backed_weight, 0);
+        assert!(bar.release(&WEIGHT).is_err());
+    }
+
+    #[test]
+    fn test_split_then_burn_frees_the_bar_with_the_last_piece() {
+        let mut bar = bar();
+        bar.assign(Metal::Gold, &WEIGHT).unwrap();
+
+        // Split into 50, 30 and 20, the first piece burned, the others merged back
+        bar.release(&piece(50_000)).unwrap();
+        assert_eq!(bar.status, BarStatus::Assigned);
+        assert_eq!(bar.backed_weight, 50_000);
+        assert!(bar.release(&piece(60_000)).is_err());
+
+        bar.release(&piece(50_000)).unwrap();
+        assert_eq!(bar.status, BarStatus::Unassigned);
+    }
+
+    #[test]
+    fn test_redeem_takes_the_whole_bar() {
+        let mut bar = bar();
+        assert!(bar.redeem(&WEIGHT).is_err());
+        bar.assign(Metal::Gold, &WEIGHT).unwrap();
+
+        assert_eq!(
+            bar.redeem(&piece(50_000)).unwrap_err(),
+            NFTManagerError::BarSplit.into()
+        );
+
+        // A burned piece leaves the rest short of the bar
+        bar.release(&piece(50_000)).unwrap();
+        assert!(bar.redeem(&piece(50_000)).is_err());
+        bar.release(&piece(50_000)).unwrap();
+
+        bar.assign(Metal::Gold, &WEIGHT).unwrap();
+        bar.redeem(&WEIGHT).unwrap();
+        assert_eq!(bar.status, BarStatus::Retired);
+        assert_eq!(bar.backed_weight, 0);
+        assert!(bar.set_status(BarStatus::Assigned).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Operator allowed to register physical bars, distinct from the manager authority
+#[account]
+#[derive(InitSpace)]
+pub struct BarRegistry {
+    pub bump: u8,
+    pub operator: Pubkey,
+    pub bar_count: u64,
+}
+
+impl BarRegistry {
+    pub fn init(&mut self, operator: Pubkey, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.operator = operator;
+        self.bar_count = 0;
+
+        Ok(())
+    }
+}
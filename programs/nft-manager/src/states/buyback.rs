@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, SECONDS_PER_DAY},
+    errors::NFTManagerError,
+};
+
+/// Terms the protocol buys NFTs of a collection back on, paid from the mint fees collector.
+#[account]
+#[derive(InitSpace)]
+pub struct Buyback {
+    pub bump: u8,
+    /// Discount on the spot value in basis points
+    pub spread_bps: u16,
+    /// Most lamports paid out per UTC day, 0 to stop buybacks
+    pub daily_limit: u64,
+    /// Keep bought NFTs in the inventory for re-listing instead of burning them
+    pub keep_in_inventory: bool,
+    /// Days since the unix epoch `paid_today` belongs to
+    pub day: i64,
+    pub paid_today: u64,
+}
+
+impl Buyback {
+    pub fn set_config(
+        &mut self,
+        spread_bps: u16,
+        daily_limit: u64,
+        keep_in_inventory: bool,
+        bump: u8,
+    ) -> Result<()> {
+        require_gte!(
+            BPS_DENOMINATOR,
+            spread_bps as u64,
+            NFTManagerError::InvalidFee
+        );
+
+        self.bump = bump;
+        self.spread_bps = spread_bps;
+        self.daily_limit = daily_limit;
+        self.keep_in_inventory = keep_in_inventory;
+
+        Ok(())
+    }
+
+    pub fn calc_payout(&self, value: u64) -> Result<u64> {
+        let payout = (value as u128)
+            .checked_mul((BPS_DENOMINATOR - self.spread_bps as u64) as u128)
+            .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(NFTManagerError::Overflow)?
+            .try_into()
+            .map_err(|_| NFTManagerError::Overflow)?;
+
+        Ok(payout)
+    }
+
+    /// Adds a payout to the running total of the day, starting a new total on a new day.
+    pub fn record_payout(&mut self, payout: u64, now: i64) -> Result<()> {
+        let day = now / SECONDS_PER_DAY;
+        if day != self.day {
+            self.day = day;
+            self.paid_today = 0;
+        }
+
+        self.paid_today = self
+            .paid_today
+            .checked_add(payout)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        require_gte!(
+            self.daily_limit,
+            self.paid_today,
+            NFTManagerError::BuybackLimitExceeded
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buyback_daily_limit() {
+        let mut buyback = Buyback {
+            bump: 0,
+            spread_bps: 0,
+            daily_limit: 0,
+            keep_in_inventory: false,
+            day: 0,
+            paid_today: 0,
+        };
+
+        assert!(buyback.set_config(10_001, 1_000, false, 255).is_err());
+        buyback.set_config(250, 1_000, false, 255).unwrap();
+
+        assert_eq!(buyback.calc_payout(1_000).unwrap(), 975);
+        assert_eq!(
+            buyback.calc_payout(u64::MAX).unwrap(),
+            17_985_575_471_866_812_824
+        );
+
+        let now = SECONDS_PER_DAY * 20_000 + 100;
+        buyback.record_payout(600, now).unwrap();
+        buyback.record_payout(400, now + 1).unwrap();
+        assert!(buyback.record_payout(1, now + 2).is_err());
+
+        // The limit starts over on the next UTC day
+        buyback.record_payout(1_000, now + SECONDS_PER_DAY).unwrap();
+        assert_eq!(buyback.paid_today, 1_000);
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::BPS_DENOMINATOR, errors::NFTManagerError};
+
 #[account]
 #[derive(InitSpace)]
 pub struct FeesCollector {
@@ -7,15 +9,80 @@ pub struct FeesCollector {
     pub fractionalize_fee: u32,
     pub sell_fee: u32,
     pub fees_decimals: u8,
+    /// Charged on top of the spot value when minting, in basis points
+    pub mint_premium_bps: u16,
+    /// Flat lamports charged per minted piece
+    pub fabrication_fee: u64,
+    /// Flat lamports charged per merge
+    pub merge_fee: u64,
+    /// Share of the fractionalize fee refunded when a fractionalization is cancelled, in basis points
+    pub cancel_refund_bps: u16,
 }
 
 impl FeesCollector {
-    pub fn init(&mut self, fractionalize_fee: u32, sell_fee: u32, bump: u8) -> Result<()> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        &mut self,
+        fractionalize_fee: u32,
+        sell_fee: u32,
+        mint_premium_bps: u16,
+        fabrication_fee: u64,
+        merge_fee: u64,
+        cancel_refund_bps: u16,
+        bump: u8,
+    ) -> Result<()> {
         self.bump = bump;
         self.fractionalize_fee = fractionalize_fee;
         self.sell_fee = sell_fee;
         self.fees_decimals = 4;
+        self.mint_premium_bps = mint_premium_bps;
+        self.fabrication_fee = fabrication_fee;
+        self.merge_fee = merge_fee;
+        self.set_cancel_refund_bps(cancel_refund_bps)?;
+
+        Ok(())
+    }
+
+    /// Applies a fee expressed with `fees_decimals` decimals to an amount.
+    pub fn calc_fee(&self, amount: u64, fee: u32) -> Result<u64> {
+        let fees = amount
+            .checked_mul(fee as u64)
+            .and_then(|v| v.checked_div(10u64.pow(self.fees_decimals as u32)))
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(fees)
+    }
+
+    pub fn set_cancel_refund_bps(&mut self, cancel_refund_bps: u16) -> Result<()> {
+        require_gte!(
+            BPS_DENOMINATOR,
+            cancel_refund_bps as u64,
+            NFTManagerError::InvalidFee
+        );
+        self.cancel_refund_bps = cancel_refund_bps;
 
         Ok(())
     }
+
+    pub fn calc_cancel_refund(&self, fee: u64) -> Result<u64> {
+        let refund = (fee as u128)
+            .checked_mul(self.cancel_refund_bps as u128)
+            .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(NFTManagerError::Overflow)?
+            .try_into()
+            .map_err(|_| NFTManagerError::Overflow)?;
+
+        Ok(refund)
+    }
+
+    pub fn calc_mint_premium(&self, amount: u64) -> Result<u64> {
+        let premium = (amount as u128)
+            .checked_mul(self.mint_premium_bps as u128)
+            .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(NFTManagerError::Overflow)?
+            .try_into()
+            .map_err(|_| NFTManagerError::Overflow)?;
+
+        Ok(premium)
+    }
 }
@@ -1,34 +1,127 @@
 use anchor_lang::prelude::*;
 
-use crate::MintNFTArgs;
+use crate::{
+    states::{asset_class::Metal, lineage::Provenance},
+    NFTPartArgs,
+};
 
-#[account]
-pub struct FinalizeFractionalizeData {
-    pub mint: Pubkey,
+/// A part of a fractionalized NFT still waiting to be minted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FractionalizePart {
     pub weight: u64,
     pub name: String,
     pub symbol: String,
     pub uri: String,
+}
+
+impl FractionalizePart {
+    pub fn calculate_space(part: &NFTPartArgs) -> usize {
+        8 + 4 + part.name.len() + 4 + part.symbol.len() + 4 + part.uri.len()
+    }
+
+    pub fn space(&self) -> usize {
+        8 + 4 + self.name.len() + 4 + self.symbol.len() + 4 + self.uri.len()
+    }
+}
+
+impl From<NFTPartArgs> for FractionalizePart {
+    fn from(part: NFTPartArgs) -> Self {
+        Self {
+            weight: part.weight,
+            name: part.name,
+            symbol: part.symbol,
+            uri: part.uri,
+        }
+    }
+}
+
+/// Accounts created before the parts were kept as a list no longer deserialize, so they have to be
+/// finalized before the program is upgraded.
+#[account]
+pub struct FinalizeFractionalizeData {
+    pub mint: Pubkey,
+    pub fineness: u16,
+    pub metal: Metal,
     pub bump: u8,
+    /// Owner of the fractionalized NFT, receives the parts and the rent back once finalized
+    pub payer: Pubkey,
+    pub created_at: i64,
+    /// Provenance shared by every part minted on finalize
+    pub provenance: Provenance,
+    /// Serial of the bar backing the original, carried onto every part
+    pub bar_serial: Option<String>,
+    /// Fractionalize fee charged, partly refunded on cancel
+    pub fee: u64,
+    /// Weight and metadata of the NFT before it was fractionalized, restored on cancel
+    pub original: FractionalizePart,
+    pub parts: Vec<FractionalizePart>,
 }
 
 impl FinalizeFractionalizeData {
-    pub fn calculate_space(part_b: MintNFTArgs) -> usize {
-        let name_b = 4 + part_b.name.as_bytes().len();
-        let symbol_b = 4 + part_b.symbol.as_bytes().len();
-        let uri_b = 4 + part_b.uri.as_bytes().len();
+    const BASE_SPACE: usize = 32 + 2 + Metal::INIT_SPACE + 1 + 32 + 8 + Provenance::INIT_SPACE + 8;
+
+    /// Space with no bar serial and an empty original, grown with `space` once the original metadata is known.
+    pub fn calculate_space(parts: &[NFTPartArgs]) -> usize {
+        let parts_space: usize = parts.iter().map(FractionalizePart::calculate_space).sum();
+
+        Self::BASE_SPACE + 1 + (8 + 4 + 4 + 4) + 4 + parts_space
+    }
 
-        name_b + symbol_b + uri_b + 41
+    pub fn space(&self) -> usize {
+        let parts_space: usize = self.parts.iter().map(FractionalizePart::space).sum();
+
+        let bar_serial_space = 1 + self
+            .bar_serial
+            .as_ref()
+            .map_or(0, |serial| 4 + serial.len());
+
+        Self::BASE_SPACE + bar_serial_space + self.original.space() + 4 + parts_space
     }
 
-    pub fn init(&mut self, mint: Pubkey, part_b: MintNFTArgs, bump: u8) -> Result<()> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        &mut self,
+        mint: Pubkey,
+        fineness: u16,
+        metal: Metal,
+        parts: Vec<NFTPartArgs>,
+        provenance: Provenance,
+        bar_serial: Option<String>,
+        fee: u64,
+        original: FractionalizePart,
+        payer: Pubkey,
+        bump: u8,
+    ) -> Result<()> {
         self.mint = mint;
-        self.weight = part_b.weight;
-        self.name = part_b.name;
-        self.symbol = part_b.symbol;
-        self.uri = part_b.uri;
+        self.fineness = fineness;
+        self.metal = metal;
+        self.parts = parts.into_iter().map(FractionalizePart::from).collect();
         self.bump = bump;
+        self.payer = payer;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.provenance = provenance;
+        self.bar_serial = bar_serial;
+        self.fee = fee;
+        self.original = original;
 
         Ok(())
     }
+
+    /// Weight still waiting to be minted.
+    pub fn pending_weight(&self) -> Result<u64> {
+        self.parts.iter().try_fold(0u64, |total, part| {
+            total
+                .checked_add(part.weight)
+                .ok_or(crate::errors::NFTManagerError::Overflow.into())
+        })
+    }
+
+    /// Takes the next part to mint, in the order they were given.
+    pub fn next_part(&mut self) -> Option<FractionalizePart> {
+        if self.parts.is_empty() {
+            None
+        } else {
+            Some(self.parts.remove(0))
+        }
+    }
 }
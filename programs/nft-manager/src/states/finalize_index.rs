@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FINALIZE_EXPIRY_SECONDS, MAX_PENDING_FINALIZATIONS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum FinalizeKind {
+    Mint,
+    Fractionalize,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PendingFinalization {
+    pub finalize_data: Pubkey,
+    pub mint: Pubkey,
+    pub kind: FinalizeKind,
+    pub created_at: i64,
+}
+
+impl PendingFinalization {
+    pub fn is_overdue(&self, now: i64, expiry: i64) -> bool {
+        now.saturating_sub(self.created_at) >= expiry
+    }
+}
+
+/// Finalize accounts that have been created but not yet finalized
+#[account]
+#[derive(InitSpace)]
+pub struct FinalizeIndex {
+    pub bump: u8,
+    #[max_len(MAX_PENDING_FINALIZATIONS)]
+    pub pending: Vec<PendingFinalization>,
+}
+
+impl FinalizeIndex {
+    pub fn init(&mut self, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.pending = Vec::new();
+
+        Ok(())
+    }
+
+    /// Tracks a new finalize account. A full index drops the overdue entries, or else the oldest
+    /// one, since every finalize account can still be found on chain without it.
+    pub fn add(&mut self, pending: PendingFinalization) -> Result<()> {
+        if self.pending.len() >= MAX_PENDING_FINALIZATIONS {
+            self.pending
+                .retain(|p| !p.is_overdue(pending.created_at, FINALIZE_EXPIRY_SECONDS));
+        }
+        if self.pending.len() >= MAX_PENDING_FINALIZATIONS {
+            self.pending.remove(0);
+        }
+        self.pending.push(pending);
+
+        Ok(())
+    }
+
+    /// Entries dropped from a full index are no longer tracked, so a missing entry is not an error.
+    pub fn remove(&mut self, finalize_data: &Pubkey) {
+        self.pending.retain(|p| p.finalize_data != *finalize_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(created_at: i64) -> PendingFinalization {
+        PendingFinalization {
+            finalize_data: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            kind: FinalizeKind::Mint,
+            created_at,
+        }
+    }
+
+    #[test]
+    fn test_full_index_never_blocks() {
+        let mut index = FinalizeIndex {
+            bump: 0,
+            pending: Vec::new(),
+        };
+        let now = 10_000;
+
+        index.add(pending(now - FINALIZE_EXPIRY_SECONDS)).unwrap();
+        for _ in 1..MAX_PENDING_FINALIZATIONS {
+            index.add(pending(now)).unwrap();
+        }
+
+        // The overdue entry makes room
+        let newest = pending(now);
+        index.add(newest).unwrap();
+        assert_eq!(index.pending.len(), MAX_PENDING_FINALIZATIONS);
+        assert!(index.pending.iter().all(|p| p.created_at == now));
+
+        // Without overdue entries the oldest one goes
+        let oldest = index.pending[0];
+        index.add(pending(now + 1)).unwrap();
+        assert_eq!(index.pending.len(), MAX_PENDING_FINALIZATIONS);
+        assert!(!index.pending.contains(&oldest));
+        assert!(index.pending.contains(&newest));
+
+        index.remove(&oldest.finalize_data);
+        assert_eq!(index.pending.len(), MAX_PENDING_FINALIZATIONS);
+    }
+}
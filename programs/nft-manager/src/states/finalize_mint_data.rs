@@ -1,18 +1,34 @@
 use anchor_lang::prelude::*;
 
+/// Accounts created before `payer` and `created_at` were added no longer deserialize, so they have
+/// to be finalized before the program is upgraded.
 #[account]
 #[derive(InitSpace)]
 pub struct FinalizeMintData {
     pub mint: Pubkey,
     pub weight: u64,
+    pub fineness: u16,
     pub bump: u8,
+    /// Receives the rent back once finalized
+    pub payer: Pubkey,
+    pub created_at: i64,
 }
 
 impl FinalizeMintData {
-    pub fn init(&mut self, mint: Pubkey, weight: u64, bump: u8) -> Result<()> {
+    pub fn init(
+        &mut self,
+        mint: Pubkey,
+        weight: u64,
+        fineness: u16,
+        payer: Pubkey,
+        bump: u8,
+    ) -> Result<()> {
         self.mint = mint;
         self.weight = weight;
+        self.fineness = fineness;
         self.bump = bump;
+        self.payer = payer;
+        self.created_at = Clock::get()?.unix_timestamp;
 
         Ok(())
     }
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::NFTManagerError, states::asset_class::Metal};
+
+/// Vault holding NFTs of one metal and fineness against a fungible gram token.
+/// One gram token unit is one milligram of vaulted weight.
+#[account]
+#[derive(InitSpace)]
+pub struct GramVault {
+    pub bump: u8,
+    pub mint_bump: u8,
+    pub metal: Metal,
+    pub fineness: u16,
+    pub mint: Pubkey,
+    /// Milligrams currently vaulted, equal to the gram token supply
+    pub total_weight: u64,
+    pub nft_count: u64,
+}
+
+impl GramVault {
+    pub fn init(
+        &mut self,
+        metal: Metal,
+        fineness: u16,
+        mint: Pubkey,
+        bump: u8,
+        mint_bump: u8,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.mint_bump = mint_bump;
+        self.metal = metal;
+        self.fineness = fineness;
+        self.mint = mint;
+        self.total_weight = 0;
+        self.nft_count = 0;
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, weight: u64) -> Result<()> {
+        self.total_weight = self
+            .total_weight
+            .checked_add(weight)
+            .ok_or(NFTManagerError::Overflow)?;
+        self.nft_count = self
+            .nft_count
+            .checked_add(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self, weight: u64) -> Result<()> {
+        self.total_weight = self
+            .total_weight
+            .checked_sub(weight)
+            .ok_or(NFTManagerError::Overflow)?;
+        self.nft_count = self
+            .nft_count
+            .checked_sub(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(())
+    }
+}
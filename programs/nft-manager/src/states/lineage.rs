@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+/// Where a piece comes from, written to its metadata under the `parent`, `generation` and `root`
+/// keys. Original bars have no parent, generation 0 and are their own root.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Provenance {
+    pub parent: Pubkey,
+    pub root: Pubkey,
+    pub generation: u32,
+}
+
+impl Provenance {
+    /// Provenance of the pieces cut from `mint`.
+    pub fn child(&self, mint: Pubkey) -> Result<Self> {
+        Ok(Self {
+            parent: mint,
+            root: self.root,
+            generation: self
+                .generation
+                .checked_add(1)
+                .ok_or(crate::errors::NFTManagerError::Overflow)?,
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LineageEntry {
+    pub mint: Pubkey,
+    pub parent: Pubkey,
+    pub weight: u64,
+    pub generation: u32,
+}
+
+/// Every piece cut from an original bar, with its current weight
+#[account]
+pub struct Lineage {
+    pub bump: u8,
+    pub root: Pubkey,
+    pub entries: Vec<LineageEntry>,
+}
+
+impl Lineage {
+    pub fn calculate_space(entries: usize) -> usize {
+        1 + 32 + 4 + entries * LineageEntry::INIT_SPACE
+    }
+
+    pub fn init(&mut self, root: Pubkey, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.root = root;
+        self.entries = Vec::new();
+
+        Ok(())
+    }
+
+    pub fn update_weight(&mut self, mint: Pubkey, weight: u64) -> Result<()> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.mint == mint)
+            .ok_or(crate::errors::NFTManagerError::InvalidLineage)?;
+        entry.weight = weight;
+
+        Ok(())
+    }
+
+    /// Adds a piece, or updates its weight when it is already recorded.
+    pub fn record(&mut self, mint: Pubkey, provenance: &Provenance, weight: u64) -> Result<()> {
+        match self.entries.iter_mut().find(|entry| entry.mint == mint) {
+            Some(entry) => entry.weight = weight,
+            None => self.entries.push(LineageEntry {
+                mint,
+                parent: provenance.parent,
+                weight,
+                generation: provenance.generation,
+            }),
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,21 @@
+pub mod admin_price;
+pub mod asset_class;
+pub mod attestation;
+pub mod bar_record;
+pub mod bar_registry;
+pub mod buyback;
 pub mod fees_collector;
 pub mod finalize_fractionalize_data;
+pub mod finalize_index;
 pub mod finalize_mint_data;
+pub mod gram_vault;
+pub mod lineage;
 pub mod listing;
 pub mod mint_fees_collector;
 pub mod nft_manager;
+pub mod oracle_config;
+pub mod payment_token;
+pub mod redemption_request;
+pub mod reserve_ledger;
 pub mod user;
+pub mod vaulted_nft;
@@ -1,6 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 
-use crate::errors::NFTManagerError;
+use crate::{constants::MAX_COLLECTION_ID_LEN, errors::NFTManagerError};
 
 #[account]
 #[derive(InitSpace)]
@@ -10,19 +10,71 @@ pub struct NFTManager {
     pub collection: Pubkey,
     pub bump: u8,
     pub discriminant: u64,
+    /// Seeds every PDA scoped to this collection. Empty for the default collection, whose admin
+    /// also manages the shared oracle config, asset classes, payment tokens and gram vaults.
+    #[max_len(MAX_COLLECTION_ID_LEN)]
+    pub collection_id: String,
+}
+
+/// Layout of the manager before collections were scoped.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct NFTManagerV1 {
+    pub authority: Pubkey,
+    pub future_authority: Option<Pubkey>,
+    pub collection: Pubkey,
+    pub bump: u8,
+    pub discriminant: u64,
 }
 
 impl NFTManager {
-    pub fn init(&mut self, authority: Pubkey, bump: u8, mint: Pubkey) -> Result<()> {
+    pub fn init(
+        &mut self,
+        authority: Pubkey,
+        bump: u8,
+        mint: Pubkey,
+        collection_id: String,
+    ) -> Result<()> {
+        require!(
+            collection_id.len() <= MAX_COLLECTION_ID_LEN,
+            NFTManagerError::InvalidCollectionId
+        );
+
         self.authority = authority;
         self.bump = bump;
         self.collection = mint;
         self.discriminant = 0;
         self.future_authority = None;
+        self.collection_id = collection_id;
 
         Ok(())
     }
 
+    /// Rewrites a manager stored in the legacy layout into the current one, as the default
+    /// collection. `data` is the whole account, already sized for the current layout. Everything
+    /// after the discriminator is rewritten, since a cleared `future_authority` leaves stale bytes
+    /// behind the legacy fields.
+    pub fn migrate(data: &mut [u8]) -> Result<Self> {
+        require!(
+            data.starts_with(&NFTManager::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let legacy = NFTManagerV1::deserialize(&mut &data[8..])?;
+        let nft_manager = NFTManager {
+            authority: legacy.authority,
+            future_authority: legacy.future_authority,
+            collection: legacy.collection,
+            bump: legacy.bump,
+            discriminant: legacy.discriminant,
+            collection_id: String::new(),
+        };
+
+        data[8..].fill(0);
+        nft_manager.serialize(&mut &mut data[8..])?;
+
+        Ok(nft_manager)
+    }
+
     pub fn set_authority(&mut self, new_authority: Pubkey) -> Result<()> {
         require!(
             self.authority != new_authority,
@@ -49,3 +101,43 @@ impl NFTManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_after_ownership_transfer() {
+        let authority = Pubkey::new_unique();
+        let mut legacy = NFTManagerV1 {
+            authority: Pubkey::new_unique(),
+            future_authority: Some(authority),
+            collection: Pubkey::new_unique(),
+            bump: 254,
+            discriminant: 7,
+        };
+
+        let mut data = NFTManager::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+
+        // Finishing the transfer shrinks the serialized data but leaves the old key behind
+        legacy.authority = authority;
+        legacy.future_authority = None;
+        legacy.serialize(&mut &mut data[8..]).unwrap();
+        data.resize(8 + NFTManager::INIT_SPACE, 0);
+
+        let migrated = NFTManager::migrate(&mut data).unwrap();
+        assert_eq!(migrated.authority, authority);
+        assert_eq!(migrated.future_authority, None);
+        assert_eq!(migrated.discriminant, 7);
+
+        let nft_manager = NFTManager::try_deserialize(&mut &data[..]).unwrap();
+        assert!(nft_manager.collection_id.is_empty());
+        assert_eq!(nft_manager.bump, 254);
+
+        // Migrating again leaves the account as it is
+        let before = data.clone();
+        NFTManager::migrate(&mut data).unwrap();
+        assert_eq!(data, before);
+    }
+}
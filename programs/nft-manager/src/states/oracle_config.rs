@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, MAX_PRICE_UPDATE_OWNERS},
+    errors::NFTManagerError,
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct OracleConfig {
+    pub bump: u8,
+    pub sol_feed_id: [u8; 32],
+    pub max_age: u64,
+    pub max_conf_bps: u16,
+    #[max_len(MAX_PRICE_UPDATE_OWNERS)]
+    pub price_update_owners: Vec<Pubkey>,
+    /// Feed checked against `sol_feed_id` when set, either a Pyth feed or an `AdminPrice`
+    pub secondary_sol_feed_id: Option<[u8; 32]>,
+    /// Largest allowed gap between the primary and secondary price, relative to the primary
+    pub max_deviation_bps: u16,
+    /// Price off the primary source alone when the secondary one is missing or unusable
+    pub allow_primary_fallback: bool,
+}
+
+impl OracleConfig {
+    pub fn init(
+        &mut self,
+        sol_feed_id: [u8; 32],
+        max_age: u64,
+        max_conf_bps: u16,
+        price_update_owners: Vec<Pubkey>,
+        max_deviation_bps: u16,
+        bump: u8,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.sol_feed_id = sol_feed_id;
+        self.secondary_sol_feed_id = None;
+        self.allow_primary_fallback = false;
+        self.set_max_age(max_age)?;
+        self.set_max_conf_bps(max_conf_bps)?;
+        self.set_price_update_owners(price_update_owners)?;
+        self.set_max_deviation_bps(max_deviation_bps)?;
+
+        Ok(())
+    }
+
+    pub fn set_max_age(&mut self, max_age: u64) -> Result<()> {
+        require_gt!(max_age, 0, NFTManagerError::InvalidOracleConfig);
+        self.max_age = max_age;
+
+        Ok(())
+    }
+
+    /// The confidence band must stay strictly below the price, otherwise `price - conf` would
+    /// reach zero when converting to lamports.
+    pub fn set_max_conf_bps(&mut self, max_conf_bps: u16) -> Result<()> {
+        require!(
+            max_conf_bps > 0 && (max_conf_bps as u64) < BPS_DENOMINATOR,
+            NFTManagerError::InvalidOracleConfig
+        );
+        self.max_conf_bps = max_conf_bps;
+
+        Ok(())
+    }
+
+    pub fn set_max_deviation_bps(&mut self, max_deviation_bps: u16) -> Result<()> {
+        require!(
+            max_deviation_bps > 0 && (max_deviation_bps as u64) < BPS_DENOMINATOR,
+            NFTManagerError::InvalidOracleConfig
+        );
+        self.max_deviation_bps = max_deviation_bps;
+
+        Ok(())
+    }
+
+    pub fn set_price_update_owners(&mut self, price_update_owners: Vec<Pubkey>) -> Result<()> {
+        require!(
+            !price_update_owners.is_empty() && price_update_owners.len() <= MAX_PRICE_UPDATE_OWNERS,
+            NFTManagerError::InvalidOracleConfig
+        );
+        self.price_update_owners = price_update_owners;
+
+        Ok(())
+    }
+
+    pub fn is_allowed_owner(&self, owner: &Pubkey) -> bool {
+        self.price_update_owners.contains(owner)
+    }
+}
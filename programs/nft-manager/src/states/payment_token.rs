@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Whitelisted USD pegged token accepted as payment for minting
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentToken {
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub enabled: bool,
+}
+
+impl PaymentToken {
+    pub fn init(
+        &mut self,
+        mint: Pubkey,
+        token_program: Pubkey,
+        enabled: bool,
+        bump: u8,
+        vault_bump: u8,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.vault_bump = vault_bump;
+        self.mint = mint;
+        self.token_program = token_program;
+        self.enabled = enabled;
+
+        Ok(())
+    }
+}
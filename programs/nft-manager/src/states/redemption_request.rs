@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::NFTManagerError, states::asset_class::Metal, utils::Weight};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum RedemptionStatus {
+    /// The NFT is escrowed until the operator fulfills or rejects the request
+    Pending,
+    /// The NFT was burned and the metal handed over
+    Fulfilled,
+    /// The NFT went back to its owner, who may request again
+    Rejected,
+}
+
+/// Physical delivery asked by the holder of an NFT, one per mint.
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionRequest {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub discriminant: u64,
+    pub metal: Metal,
+    /// Gross weight in milligrams
+    pub weight: u64,
+    pub fineness: u16,
+    /// Hash of the delivery details kept off-chain
+    pub delivery_hash: [u8; 32],
+    pub status: RedemptionStatus,
+    pub requested_at: i64,
+    pub resolved_at: i64,
+}
+
+impl RedemptionRequest {
+    #[allow(clippy::too_many_arguments)]
+    pub fn open(
+        &mut self,
+        owner: Pubkey,
+        mint: Pubkey,
+        discriminant: u64,
+        metal: Metal,
+        weight: &Weight,
+        delivery_hash: [u8; 32],
+        bump: u8,
+    ) -> Result<()> {
+        // A fresh account or one whose last request was rejected
+        require!(
+            self.owner == Pubkey::default() || self.status == RedemptionStatus::Rejected,
+            NFTManagerError::RedemptionAlreadyRequested
+        );
+
+        self.bump = bump;
+        self.owner = owner;
+        self.mint = mint;
+        self.discriminant = discriminant;
+        self.metal = metal;
+        self.weight = weight.milligrams;
+        self.fineness = weight.fineness;
+        self.delivery_hash = delivery_hash;
+        self.status = RedemptionStatus::Pending;
+        self.requested_at = Clock::get()?.unix_timestamp;
+        self.resolved_at = 0;
+
+        Ok(())
+    }
+
+    pub fn resolve(&mut self, status: RedemptionStatus) -> Result<()> {
+        require!(
+            self.status == RedemptionStatus::Pending,
+            NFTManagerError::RedemptionNotPending
+        );
+
+        self.status = status;
+        self.resolved_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+}
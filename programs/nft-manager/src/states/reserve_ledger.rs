@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::NFTManagerError, states::asset_class::Metal};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct CollectionReserve {
+    pub collection: Pubkey,
+    pub metal: Metal,
+    pub minted_weight: u64,
+    pub burned_weight: u64,
+    pub live_nft_count: u64,
+}
+
+impl CollectionReserve {
+    /// Weight, in milligrams, the collection owes to its holders.
+    pub fn outstanding_weight(&self) -> Result<u64> {
+        self.minted_weight
+            .checked_sub(self.burned_weight)
+            .ok_or(NFTManagerError::Overflow.into())
+    }
+}
+
+/// Weight issued and retired across every collection. Fractionalizing and merging keep the
+/// outstanding weight and only change the live NFT count.
+#[account]
+pub struct ReserveLedger {
+    pub bump: u8,
+    pub total_minted_weight: u64,
+    pub total_burned_weight: u64,
+    pub live_nft_count: u64,
+    pub collections: Vec<CollectionReserve>,
+}
+
+impl ReserveLedger {
+    pub fn calculate_space(collections: usize) -> usize {
+        1 + 8 + 8 + 8 + 4 + collections * CollectionReserve::INIT_SPACE
+    }
+
+    /// Opening balances let a deployment with NFTs already outstanding start from its real
+    /// liabilities.
+    pub fn init(&mut self, bump: u8, opening: Vec<CollectionReserve>) -> Result<()> {
+        self.bump = bump;
+        self.total_minted_weight = 0;
+        self.total_burned_weight = 0;
+        self.live_nft_count = 0;
+
+        for entry in opening.iter() {
+            entry.outstanding_weight()?;
+
+            self.total_minted_weight = self
+                .total_minted_weight
+                .checked_add(entry.minted_weight)
+                .ok_or(NFTManagerError::Overflow)?;
+            self.total_burned_weight = self
+                .total_burned_weight
+                .checked_add(entry.burned_weight)
+                .ok_or(NFTManagerError::Overflow)?;
+            self.live_nft_count = self
+                .live_nft_count
+                .checked_add(entry.live_nft_count)
+                .ok_or(NFTManagerError::Overflow)?;
+        }
+
+        self.collections = opening;
+
+        Ok(())
+    }
+
+    pub fn outstanding_weight(&self) -> Result<u64> {
+        self.total_minted_weight
+            .checked_sub(self.total_burned_weight)
+            .ok_or(NFTManagerError::Overflow.into())
+    }
+
+    /// Weight, in milligrams, owed to holders of one metal across every collection.
+    pub fn outstanding_weight_of(&self, metal: Metal) -> Result<u64> {
+        self.collections
+            .iter()
+            .filter(|entry| entry.metal == metal)
+            .try_fold(0u64, |total, entry| {
+                total
+                    .checked_add(entry.outstanding_weight()?)
+                    .ok_or(NFTManagerError::Overflow.into())
+            })
+    }
+
+    fn collection_mut(&mut self, collection: Pubkey, metal: Metal) -> &mut CollectionReserve {
+        let index = match self
+            .collections
+            .iter()
+            .position(|entry| entry.collection == collection && entry.metal == metal)
+        {
+            Some(index) => index,
+            None => {
+                self.collections.push(CollectionReserve {
+                    collection,
+                    metal,
+                    minted_weight: 0,
+                    burned_weight: 0,
+                    live_nft_count: 0,
+                });
+                self.collections.len() - 1
+            }
+        };
+
+        &mut self.collections[index]
+    }
+
+    /// Only minting opens an entry, every other change applies to weight already recorded.
+    fn existing_collection_mut(
+        &mut self,
+        collection: Pubkey,
+        metal: Metal,
+    ) -> Result<&mut CollectionReserve> {
+        self.collections
+            .iter_mut()
+            .find(|entry| entry.collection == collection && entry.metal == metal)
+            .ok_or(NFTManagerError::ReserveEntryNotFound.into())
+    }
+
+    pub fn record_mint(&mut self, collection: Pubkey, metal: Metal, weight: u64) -> Result<()> {
+        self.total_minted_weight = self
+            .total_minted_weight
+            .checked_add(weight)
+            .ok_or(NFTManagerError::Overflow)?;
+        self.live_nft_count = self
+            .live_nft_count
+            .checked_add(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        let entry = self.collection_mut(collection, metal);
+        entry.minted_weight = entry
+            .minted_weight
+            .checked_add(weight)
+            .ok_or(NFTManagerError::Overflow)?;
+        entry.live_nft_count = entry
+            .live_nft_count
+            .checked_add(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(())
+    }
+
+    pub fn record_burn(&mut self, collection: Pubkey, metal: Metal, weight: u64) -> Result<()> {
+        let entry = self.existing_collection_mut(collection, metal)?;
+        entry.burned_weight = entry
+            .burned_weight
+            .checked_add(weight)
+            .ok_or(NFTManagerError::Overflow)?;
+        entry.live_nft_count = entry
+            .live_nft_count
+            .checked_sub(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        self.total_burned_weight = self
+            .total_burned_weight
+            .checked_add(weight)
+            .ok_or(NFTManagerError::Overflow)?;
+        self.live_nft_count = self
+            .live_nft_count
+            .checked_sub(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// A piece cut from an existing NFT was minted.
+    pub fn record_split(&mut self, collection: Pubkey, metal: Metal) -> Result<()> {
+        let entry = self.existing_collection_mut(collection, metal)?;
+        entry.live_nft_count = entry
+            .live_nft_count
+            .checked_add(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        self.live_nft_count = self
+            .live_nft_count
+            .checked_add(1)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// `merged` NFTs were burned into a single new one.
+    pub fn record_merge(&mut self, collection: Pubkey, metal: Metal, merged: u64) -> Result<()> {
+        let removed = merged.checked_sub(1).ok_or(NFTManagerError::Overflow)?;
+
+        let entry = self.existing_collection_mut(collection, metal)?;
+        entry.live_nft_count = entry
+            .live_nft_count
+            .checked_sub(removed)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        self.live_nft_count = self
+            .live_nft_count
+            .checked_sub(removed)
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burn_requires_a_minted_entry() {
+        let mut ledger = ReserveLedger {
+            bump: 0,
+            total_minted_weight: 0,
+            total_burned_weight: 0,
+            live_nft_count: 0,
+            collections: Vec::new(),
+        };
+        let collection = Pubkey::new_unique();
+
+        assert_eq!(
+            ledger.record_burn(collection, Metal::Gold, 10).unwrap_err(),
+            NFTManagerError::ReserveEntryNotFound.into()
+        );
+        assert!(ledger.record_split(collection, Metal::Gold).is_err());
+
+        ledger.record_mint(collection, Metal::Gold, 10).unwrap();
+        assert!(ledger.record_burn(collection, Metal::Silver, 10).is_err());
+
+        ledger.record_burn(collection, Metal::Gold, 10).unwrap();
+        assert_eq!(ledger.collections.len(), 1);
+        assert_eq!(ledger.outstanding_weight_of(Metal::Gold).unwrap(), 0);
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// NFT deposited in a gram vault, withdrawable by anyone burning its weight in gram tokens
+#[account]
+#[derive(InitSpace)]
+pub struct VaultedNFT {
+    pub bump: u8,
+    pub token_account_bump: u8,
+    pub mint: Pubkey,
+    pub gram_vault: Pubkey,
+    /// Milligrams recorded at deposit
+    pub weight: u64,
+    /// Paid the rent of the vault accounts and gets it back on withdrawal
+    pub depositor: Pubkey,
+}
+
+impl VaultedNFT {
+    pub fn init(
+        &mut self,
+        mint: Pubkey,
+        gram_vault: Pubkey,
+        weight: u64,
+        depositor: Pubkey,
+        bump: u8,
+        token_account_bump: u8,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.token_account_bump = token_account_bump;
+        self.mint = mint;
+        self.gram_vault = gram_vault;
+        self.weight = weight;
+        self.depositor = depositor;
+
+        Ok(())
+    }
+}
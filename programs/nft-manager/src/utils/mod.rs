@@ -8,49 +8,103 @@ use anchor_lang::{
 };
 use anchor_spl::{
     token_2022::spl_token_2022::{
-        extension::{BaseStateWithExtensions, PodStateWithExtensions},
+        extension::{BaseStateWithExtensions, ExtensionType, PodStateWithExtensions},
         pod::PodMint,
+        state::Mint,
     },
     token_interface,
 };
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, Price, PriceUpdateV2};
-use spl_token_metadata_interface::state::TokenMetadata;
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+use spl_token_group_interface::state::TokenGroupMember;
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 
 use crate::{
-    constants::{COLLECTION_KEY, DISCRIMINANT_KEY, MAX_AGE, SOL_PRICE_FEED_ID_HEX, WEIGHT_KEY},
+    constants::{
+        BAR_SERIAL_KEY, BPS_DENOMINATOR, COLLECTION_KEY, COLLECTION_TAG, DISCRIMINANT_KEY,
+        FINENESS_KEY, GENERATION_KEY, LEGACY_WEIGHT_UNITS_PER_TROY_OUNCE, LIST_NFT_PRICE_DECIMALS,
+        MAX_FINENESS, METAL_KEY, MICROGRAMS_PER_MILLIGRAM, PARENT_KEY, ROOT_KEY,
+        TROY_OUNCE_MICROGRAMS, WEIGHT_KEY,
+    },
     errors::NFTManagerError,
-    states::nft_manager::NFTManager,
+    states::{
+        admin_price::AdminPrice,
+        asset_class::{AssetClass, Metal},
+        lineage::Provenance,
+        nft_manager::NFTManager,
+        oracle_config::OracleConfig,
+    },
 };
 
+/// Gross weight of a piece in milligrams together with its fineness in parts per
+/// `MAX_FINENESS` (e.g. 9999 for 999.9 gold).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Weight {
+    pub milligrams: u64,
+    pub fineness: u16,
+}
+
+impl Weight {
+    pub fn new(milligrams: u64, fineness: u16) -> Result<Self> {
+        require_gt!(milligrams, 0, NFTManagerError::InvalidWeight);
+        require!(
+            fineness > 0 && fineness <= MAX_FINENESS,
+            NFTManagerError::InvalidFineness
+        );
+
+        Ok(Self {
+            milligrams,
+            fineness,
+        })
+    }
+
+    /// Weight of the pure metal contained in the piece, in milligrams.
+    pub fn fine_milligrams(&self) -> Result<u64> {
+        let fine = (self.milligrams as u128)
+            .checked_mul(self.fineness as u128)
+            .and_then(|v| v.checked_div(MAX_FINENESS as u128))
+            .ok_or(NFTManagerError::Overflow)?;
+
+        Ok(fine as u64)
+    }
+}
+
 // #[inline(always)]
-pub fn calc_gold_value_in_lamport(gold_price: Price, sol_price: Price, weight: u64) -> Result<u64> {
-    // Ensure weight is non-zero to avoid unnecessary computations
-    require_gte!(weight, 0, NFTManagerError::InvalidWeight);
-    require_gt!(gold_price.price, 0, NFTManagerError::NegativePrice);
+pub fn calc_metal_value_in_lamport(
+    metal_price: Price,
+    sol_price: Price,
+    weight: &Weight,
+    price_unit_micrograms: u64,
+) -> Result<u64> {
+    require_gt!(price_unit_micrograms, 0, NFTManagerError::InvalidAssetClass);
+    require_gt!(metal_price.price, 0, NFTManagerError::NegativePrice);
     require_gt!(sol_price.price, 0, NFTManagerError::NegativePrice);
 
-    let mut numerator = gold_price.price as u128;
+    let mut numerator = metal_price.price as u128;
     let mut denominator = sol_price.price as u128;
 
+    // Price is quoted per `price_unit_micrograms` of pure metal
     numerator = numerator
-        .checked_add(gold_price.conf as u128)
-        .and_then(|v| v.checked_mul(weight as u128))
+        .checked_add(metal_price.conf as u128)
+        .and_then(|v| v.checked_mul(weight.milligrams as u128))
+        .and_then(|v| v.checked_mul(weight.fineness as u128))
+        .and_then(|v| v.checked_mul(MICROGRAMS_PER_MILLIGRAM as u128))
         .and_then(|v| v.checked_mul(LAMPORTS_PER_SOL as u128))
         .ok_or(NFTManagerError::Overflow)?;
 
     denominator = denominator
         .checked_sub(sol_price.conf as u128)
-        .and_then(|v| v.checked_mul(2_83))
+        .and_then(|v| v.checked_mul(price_unit_micrograms as u128))
+        .and_then(|v| v.checked_mul(MAX_FINENESS as u128))
         .ok_or(NFTManagerError::Overflow)?;
 
-    if gold_price.exponent > sol_price.exponent {
+    if metal_price.exponent > sol_price.exponent {
         numerator = numerator
-            .checked_mul(10u128.pow((gold_price.exponent - sol_price.exponent) as u32))
+            .checked_mul(10u128.pow((metal_price.exponent - sol_price.exponent) as u32))
             .ok_or(NFTManagerError::Overflow)?;
     } else {
         denominator = denominator
-            .checked_mul(10u128.pow((sol_price.exponent - gold_price.exponent) as u32))
+            .checked_mul(10u128.pow((sol_price.exponent - metal_price.exponent) as u32))
             .ok_or(NFTManagerError::Overflow)?;
     }
 
@@ -63,55 +117,355 @@ pub fn calc_gold_value_in_lamport(gold_price: Price, sol_price: Price, weight: u
     Ok(lamport as u64)
 }
 
-#[inline(always)]
-pub fn get_gold_value_in_lamport(
-    gold_price_update: &Account<PriceUpdateV2>,
-    sol_price_update: &Account<PriceUpdateV2>,
-    weight: u64,
+/// Values a piece in base units of a USD pegged token with `decimals` decimals.
+pub fn calc_metal_value_in_token(
+    metal_price: Price,
+    weight: &Weight,
+    price_unit_micrograms: u64,
+    decimals: u8,
 ) -> Result<u64> {
-    let gold_feed_id: [u8; 32] =
-        get_feed_id_from_hex("765d2ba906dbc32ca17cc11f5310a89e9ee1f6420508c63861f2f8ba4ee34bb2")?;
-    let sol_feed_id: [u8; 32] = get_feed_id_from_hex(SOL_PRICE_FEED_ID_HEX)?;
-    let gold_price =
-        gold_price_update.get_price_no_older_than(&Clock::get()?, MAX_AGE, &gold_feed_id)?;
-    let sol_price =
-        sol_price_update.get_price_no_older_than(&Clock::get()?, MAX_AGE, &sol_feed_id)?;
+    require_gt!(price_unit_micrograms, 0, NFTManagerError::InvalidAssetClass);
+    require_gt!(metal_price.price, 0, NFTManagerError::NegativePrice);
+
+    let mut numerator = (metal_price.price as u128)
+        .checked_add(metal_price.conf as u128)
+        .and_then(|v| v.checked_mul(weight.milligrams as u128))
+        .and_then(|v| v.checked_mul(weight.fineness as u128))
+        .and_then(|v| v.checked_mul(MICROGRAMS_PER_MILLIGRAM as u128))
+        .ok_or(NFTManagerError::Overflow)?;
+
+    let mut denominator = (price_unit_micrograms as u128)
+        .checked_mul(MAX_FINENESS as u128)
+        .ok_or(NFTManagerError::Overflow)?;
 
-    calc_gold_value_in_lamport(gold_price, sol_price, weight)
+    let exponent = metal_price.exponent + decimals as i32;
+
+    if exponent < 0 {
+        denominator = denominator
+            .checked_mul(10u128.pow(exponent.unsigned_abs()))
+            .ok_or(NFTManagerError::Overflow)?;
+    } else {
+        numerator = numerator
+            .checked_mul(10u128.pow(exponent as u32))
+            .ok_or(NFTManagerError::Overflow)?;
+    }
+
+    let amount: u64 = numerator
+        .checked_div(denominator)
+        .ok_or(NFTManagerError::Overflow)?
+        .try_into()
+        .map_err(|_| NFTManagerError::Overflow)?;
+
+    require_gt!(amount, 0, NFTManagerError::PriceCalculationFail);
+
+    Ok(amount)
 }
 
-pub fn validate_weight(additional_metadata: &[(String, String)]) -> Result<()> {
-    let weight = additional_metadata
-        .iter()
-        .find(|(key, _)| key == WEIGHT_KEY)
-        .ok_or(NFTManagerError::InvalidMetadata)?
-        .1
-        .parse::<u64>()
-        .map_err(|_| NFTManagerError::InvalidMetadata)?;
+/// Rejects prices whose confidence interval is wider than `max_conf_bps` of the price itself.
+pub fn validate_price_confidence(price: &Price, max_conf_bps: u16) -> Result<()> {
+    require_gt!(price.price, 0, NFTManagerError::NegativePrice);
 
-    require_gt!(weight, 0, NFTManagerError::InvalidWeight);
+    let max_conf = (price.price as u128)
+        .checked_mul(max_conf_bps as u128)
+        .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+        .ok_or(NFTManagerError::Overflow)?;
+
+    require_gte!(
+        max_conf,
+        price.conf as u128,
+        NFTManagerError::PriceConfidenceTooWide
+    );
 
     Ok(())
 }
 
-pub fn validate_fractions(
-    weight: u64,
-    fraction_a_weight: u64,
-    fraction_b_weight: u64,
+/// Reads a price from a `PriceUpdateV2` account after checking that it is owned by one of the
+/// programs allowed in the oracle config, that it is no older than the configured max age and
+/// that its confidence interval is within the configured bound.
+pub fn get_price(
+    price_update: &AccountInfo,
+    oracle_config: &OracleConfig,
+    feed_id: &[u8; 32],
+) -> Result<Price> {
+    require!(
+        oracle_config.is_allowed_owner(price_update.owner),
+        NFTManagerError::InvalidPriceUpdateOwner
+    );
+
+    let data = price_update.try_borrow_data()?;
+    let price_update = PriceUpdateV2::try_deserialize(&mut &data[..])?;
+
+    let price =
+        price_update.get_price_no_older_than(&Clock::get()?, oracle_config.max_age, feed_id)?;
+
+    validate_price_confidence(&price, oracle_config.max_conf_bps)?;
+
+    Ok(price)
+}
+
+/// Reads a price pushed by the admin, held to the same max age and confidence bound as the
+/// oracle prices.
+pub fn get_admin_price(
+    admin_price: &AccountInfo,
+    oracle_config: &OracleConfig,
+    feed_id: &[u8; 32],
+) -> Result<Price> {
+    require_keys_eq!(
+        *admin_price.owner,
+        crate::ID,
+        NFTManagerError::InvalidAdminPrice
+    );
+
+    let data = admin_price.try_borrow_data()?;
+    let admin_price = AdminPrice::try_deserialize(&mut &data[..])?;
+
+    require!(
+        admin_price.feed_id == *feed_id,
+        NFTManagerError::InvalidAdminPrice
+    );
+
+    let age = Clock::get()?
+        .unix_timestamp
+        .checked_sub(admin_price.publish_time)
+        .ok_or(NFTManagerError::Overflow)?;
+
+    require!(
+        age >= 0 && age as u64 <= oracle_config.max_age,
+        NFTManagerError::StalePrice
+    );
+
+    let price = admin_price.to_price();
+
+    validate_price_confidence(&price, oracle_config.max_conf_bps)?;
+
+    Ok(price)
+}
+
+/// Rejects two prices of the same asset that are further apart than `max_deviation_bps` of the
+/// primary price.
+pub fn validate_price_deviation(
+    primary: &Price,
+    secondary: &Price,
+    max_deviation_bps: u16,
 ) -> Result<()> {
-    require_eq!(
-        weight,
-        fraction_a_weight + fraction_b_weight,
-        NFTManagerError::InvalidWeight
+    require_gt!(primary.price, 0, NFTManagerError::NegativePrice);
+    require_gt!(secondary.price, 0, NFTManagerError::NegativePrice);
+
+    let mut primary_price = primary.price as u128;
+    let mut secondary_price = secondary.price as u128;
+
+    // Bring both prices to the smaller exponent
+    if primary.exponent > secondary.exponent {
+        primary_price = primary_price
+            .checked_mul(10u128.pow((primary.exponent - secondary.exponent) as u32))
+            .ok_or(NFTManagerError::Overflow)?;
+    } else {
+        secondary_price = secondary_price
+            .checked_mul(10u128.pow((secondary.exponent - primary.exponent) as u32))
+            .ok_or(NFTManagerError::Overflow)?;
+    }
+
+    let max_deviation = primary_price
+        .checked_mul(max_deviation_bps as u128)
+        .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+        .ok_or(NFTManagerError::Overflow)?;
+
+    require_gte!(
+        max_deviation,
+        primary_price.abs_diff(secondary_price),
+        NFTManagerError::PriceDeviationTooWide
     );
 
-    require_gte!(fraction_a_weight, 1, NFTManagerError::InvalidWeight);
-    require_gte!(fraction_b_weight, 1, NFTManagerError::InvalidWeight);
+    Ok(())
+}
+
+/// Reads the primary price and, when a secondary feed is configured, checks it against the
+/// secondary source. Secondary accounts owned by this program are read as `AdminPrice`, any
+/// other as a `PriceUpdateV2`. A missing or unusable secondary price only falls back to the
+/// primary when the oracle config allows it, a deviating one never does.
+pub fn get_checked_price(
+    price_update: &AccountInfo,
+    secondary_price_update: Option<&AccountInfo>,
+    oracle_config: &OracleConfig,
+    feed_id: &[u8; 32],
+    secondary_feed_id: Option<[u8; 32]>,
+) -> Result<Price> {
+    let price = get_price(price_update, oracle_config, feed_id)?;
+
+    let Some(secondary_feed_id) = secondary_feed_id else {
+        return Ok(price);
+    };
+
+    let secondary_price = match secondary_price_update {
+        Some(account) if *account.owner == crate::ID => {
+            get_admin_price(account, oracle_config, &secondary_feed_id)
+        }
+        Some(account) => get_price(account, oracle_config, &secondary_feed_id),
+        None => err!(NFTManagerError::SecondaryPriceUnavailable),
+    };
+
+    match secondary_price {
+        Ok(secondary_price) => {
+            validate_price_deviation(&price, &secondary_price, oracle_config.max_deviation_bps)?
+        }
+        Err(_) if oracle_config.allow_primary_fallback => {
+            msg!("Secondary price unavailable, using primary price only");
+        }
+        Err(e) => return Err(e),
+    }
+
+    Ok(price)
+}
+
+/// Converts a USD amount with `LIST_NFT_PRICE_DECIMALS` decimals into lamports.
+pub fn calc_usd_value_in_lamport(price: u64, sol_price: Price) -> Result<u64> {
+    require_gt!(sol_price.price, 0, NFTManagerError::NegativePrice);
+
+    let mut numerator = (price as u128)
+        .checked_mul(LAMPORTS_PER_SOL as u128)
+        .ok_or(NFTManagerError::Overflow)?;
+    let mut denominator = (sol_price.price as u128)
+        .checked_sub(sol_price.conf as u128)
+        .ok_or(NFTManagerError::Overflow)?;
+
+    let exponent_diff = -(LIST_NFT_PRICE_DECIMALS as i32) - sol_price.exponent;
+
+    if exponent_diff < 0 {
+        denominator = denominator
+            .checked_mul(10u128.pow(exponent_diff.unsigned_abs()))
+            .ok_or(NFTManagerError::Overflow)?;
+    } else {
+        numerator = numerator
+            .checked_mul(10u128.pow(exponent_diff as u32))
+            .ok_or(NFTManagerError::Overflow)?;
+    }
+
+    let lamports = numerator
+        .checked_div(denominator)
+        .ok_or(NFTManagerError::Overflow)?
+        .try_into()
+        .map_err(|_| NFTManagerError::Overflow)?;
+
+    Ok(lamports)
+}
+
+/// Lamport value of a piece together with the publish times of the prices it was derived from.
+pub struct MetalValue {
+    pub lamports: u64,
+    pub metal_publish_time: i64,
+    pub sol_publish_time: i64,
+}
+
+/// Primary and optional secondary price accounts for one asset.
+#[derive(Clone, Copy)]
+pub struct PriceSource<'a, 'info> {
+    pub primary: &'a AccountInfo<'info>,
+    pub secondary: Option<&'a AccountInfo<'info>>,
+}
+
+pub fn get_metal_price(
+    metal: PriceSource,
+    oracle_config: &OracleConfig,
+    asset_class: &AssetClass,
+) -> Result<Price> {
+    require!(asset_class.enabled, NFTManagerError::AssetClassDisabled);
+
+    get_checked_price(
+        metal.primary,
+        metal.secondary,
+        oracle_config,
+        &asset_class.feed_id,
+        asset_class.secondary_feed_id,
+    )
+}
+
+pub fn get_sol_price(sol: PriceSource, oracle_config: &OracleConfig) -> Result<Price> {
+    get_checked_price(
+        sol.primary,
+        sol.secondary,
+        oracle_config,
+        &oracle_config.sol_feed_id,
+        oracle_config.secondary_sol_feed_id,
+    )
+}
+
+pub fn get_metal_value(
+    metal: PriceSource,
+    sol: PriceSource,
+    oracle_config: &OracleConfig,
+    asset_class: &AssetClass,
+    weight: &Weight,
+) -> Result<MetalValue> {
+    let metal_price = get_metal_price(metal, oracle_config, asset_class)?;
+    let sol_price = get_sol_price(sol, oracle_config)?;
+
+    let lamports = calc_metal_value_in_lamport(
+        metal_price,
+        sol_price,
+        weight,
+        asset_class.price_unit_micrograms,
+    )?;
+
+    Ok(MetalValue {
+        lamports,
+        metal_publish_time: metal_price.publish_time,
+        sol_publish_time: sol_price.publish_time,
+    })
+}
+
+pub fn get_metal_value_in_token(
+    metal: PriceSource,
+    oracle_config: &OracleConfig,
+    asset_class: &AssetClass,
+    weight: &Weight,
+    decimals: u8,
+) -> Result<u64> {
+    let metal_price = get_metal_price(metal, oracle_config, asset_class)?;
+
+    calc_metal_value_in_token(
+        metal_price,
+        weight,
+        asset_class.price_unit_micrograms,
+        decimals,
+    )
+}
+
+#[inline(always)]
+pub fn get_metal_value_in_lamport(
+    metal: PriceSource,
+    sol: PriceSource,
+    oracle_config: &OracleConfig,
+    asset_class: &AssetClass,
+    weight: &Weight,
+) -> Result<u64> {
+    Ok(get_metal_value(metal, sol, oracle_config, asset_class, weight)?.lamports)
+}
+
+pub fn validate_weight(additional_metadata: &[(String, String)]) -> Result<Weight> {
+    let weight = get_weight(additional_metadata)?;
+
+    Weight::new(weight.milligrams, weight.fineness)
+}
+
+pub fn validate_fractions(weight: u64, fraction_weights: &[u64]) -> Result<()> {
+    let mut total: u64 = 0;
+
+    for fraction_weight in fraction_weights {
+        require_gte!(*fraction_weight, 1, NFTManagerError::InvalidWeight);
+
+        total = total
+            .checked_add(*fraction_weight)
+            .ok_or(NFTManagerError::Overflow)?;
+    }
+
+    require_eq!(weight, total, NFTManagerError::InvalidWeight);
 
     Ok(())
 }
 
-pub fn get_weight(additional_metadata: &[(String, String)]) -> Result<u64> {
+/// NFTs minted before the fineness key existed carry their weight in the legacy unit and were
+/// valued as pure metal, so they read back converted to milligrams at full fineness.
+pub fn get_weight(additional_metadata: &[(String, String)]) -> Result<Weight> {
     let weight = additional_metadata
         .iter()
         .find(|(key, _)| key == WEIGHT_KEY)
@@ -120,13 +474,93 @@ pub fn get_weight(additional_metadata: &[(String, String)]) -> Result<u64> {
         .parse::<u64>()
         .map_err(|_| NFTManagerError::InvalidMetadata)?;
 
-    Ok(weight)
+    match additional_metadata
+        .iter()
+        .find(|(key, _)| key == FINENESS_KEY)
+    {
+        Some((_, value)) => Ok(Weight {
+            milligrams: weight,
+            fineness: value
+                .parse::<u16>()
+                .map_err(|_| NFTManagerError::InvalidMetadata)?,
+        }),
+        None => Ok(Weight {
+            milligrams: legacy_weight_to_milligrams(weight)?,
+            fineness: MAX_FINENESS,
+        }),
+    }
+}
+
+/// Converts a weight in the legacy unit, a 283rd of a troy ounce, rounded to the nearest milligram.
+pub fn legacy_weight_to_milligrams(weight: u64) -> Result<u64> {
+    let divisor = LEGACY_WEIGHT_UNITS_PER_TROY_OUNCE as u128 * MICROGRAMS_PER_MILLIGRAM as u128;
+    let milligrams = (weight as u128)
+        .checked_mul(TROY_OUNCE_MICROGRAMS as u128)
+        .and_then(|v| v.checked_add(divisor / 2))
+        .and_then(|v| v.checked_div(divisor))
+        .ok_or(NFTManagerError::Overflow)?
+        .try_into()
+        .map_err(|_| NFTManagerError::Overflow)?;
+
+    Ok(milligrams)
+}
+
+/// NFTs minted before the metal key existed are all gold.
+pub fn get_metal(additional_metadata: &[(String, String)]) -> Result<Metal> {
+    match additional_metadata.iter().find(|(key, _)| key == METAL_KEY) {
+        Some((_, value)) => Ok(value.parse::<Metal>()?),
+        None => Ok(Metal::Gold),
+    }
 }
 
+/// NFTs minted before bars were registered carry no serial.
+pub fn get_bar_serial(additional_metadata: &[(String, String)]) -> Option<&str> {
+    additional_metadata
+        .iter()
+        .find(|(key, _)| key == BAR_SERIAL_KEY)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Bar serial carried onto a piece split or merged from a bar-backed NFT.
+pub fn bar_serial_metadata(serial: Option<&str>) -> Vec<(String, String)> {
+    serial
+        .map(|serial| (BAR_SERIAL_KEY.to_string(), serial.to_string()))
+        .into_iter()
+        .collect()
+}
+
+pub fn validate_metal(additional_metadata: &[(String, String)], metal: Metal) -> Result<()> {
+    require!(
+        get_metal(additional_metadata)? == metal,
+        NFTManagerError::MetalMismatch
+    );
+
+    Ok(())
+}
+
+/// Checks the NFT is a member of the collection group. NFTs minted before group membership was
+/// initialized only carry the collection key in their metadata.
 pub fn validate_collection(
+    mint: &AccountInfo,
     additional_metadata: &[(String, String)],
     collection: Pubkey,
 ) -> Result<()> {
+    let group = {
+        let buffer = mint.try_borrow_data()?;
+        let state = PodStateWithExtensions::<PodMint>::unpack(&buffer)?;
+
+        state
+            .get_extension::<TokenGroupMember>()
+            .ok()
+            .map(|member| member.group)
+    };
+
+    if let Some(group) = group {
+        require_keys_eq!(group, collection, NFTManagerError::InvalidCollection);
+
+        return Ok(());
+    }
+
     let collection_key = additional_metadata
         .iter()
         .find(|(key, _)| key == COLLECTION_KEY)
@@ -144,6 +578,139 @@ pub fn validate_collection(
     Ok(())
 }
 
+/// Pieces minted before lineage was tracked are read as original bars.
+pub fn get_provenance(
+    mint: Pubkey,
+    additional_metadata: &[(String, String)],
+) -> Result<Provenance> {
+    let find = |name: &str| {
+        additional_metadata
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    };
+
+    let parent = match find(PARENT_KEY) {
+        Some(value) => value
+            .parse::<Pubkey>()
+            .map_err(|_| NFTManagerError::InvalidMetadata)?,
+        None => Pubkey::default(),
+    };
+
+    let root = match find(ROOT_KEY) {
+        Some(value) => value
+            .parse::<Pubkey>()
+            .map_err(|_| NFTManagerError::InvalidMetadata)?,
+        None => mint,
+    };
+
+    let generation = match find(GENERATION_KEY) {
+        Some(value) => value
+            .parse::<u32>()
+            .map_err(|_| NFTManagerError::InvalidMetadata)?,
+        None => 0,
+    };
+
+    Ok(Provenance {
+        parent,
+        root,
+        generation,
+    })
+}
+
+pub fn provenance_metadata(provenance: &Provenance) -> Vec<(String, String)> {
+    vec![
+        (PARENT_KEY.to_string(), provenance.parent.to_string()),
+        (
+            GENERATION_KEY.to_string(),
+            provenance.generation.to_string(),
+        ),
+        (ROOT_KEY.to_string(), provenance.root.to_string()),
+    ]
+}
+
+/// Grows a program account to `new_len`, topping up its rent from `payer`.
+pub fn realloc_account<'a>(
+    account: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    new_len: usize,
+) -> Result<()> {
+    if new_len <= account.data_len() {
+        return Ok(());
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account.lamports();
+
+    if required_lamports > current_lamports {
+        system::transfer(
+            CpiContext::new(
+                system_program,
+                system::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    account.realloc(new_len, false)?;
+
+    Ok(())
+}
+
+/// Registers an NFT as a member of the collection group, bumping the group size. The NFT mint
+/// authority must still be set.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_group_member<'a>(
+    mint: AccountInfo<'a>,
+    collection: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    mint_signer_seeds: &[&[&[u8]]],
+    collection_id: &[u8],
+    collection_bump: u8,
+) -> Result<()> {
+    // Extension header (type + length) followed by the member
+    let required_len = mint.data_len() + 4 + std::mem::size_of::<TokenGroupMember>();
+    let required_lamports = Rent::get()?.minimum_balance(required_len);
+    let current_lamports = mint.lamports();
+
+    if required_lamports > current_lamports {
+        system::transfer(
+            CpiContext::new(
+                system_program,
+                system::Transfer {
+                    from: payer,
+                    to: mint.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    let collection_seeds: &[&[u8]] = &[COLLECTION_TAG, collection_id, &[collection_bump]];
+    let signer_seeds = [mint_signer_seeds[0], collection_seeds];
+
+    token_interface::token_member_initialize(CpiContext::new_with_signer(
+        token_program.clone(),
+        token_interface::TokenMemberInitialize {
+            token_program_id: token_program,
+            member: mint.clone(),
+            member_mint: mint.clone(),
+            member_mint_authority: mint,
+            group: collection.clone(),
+            group_update_authority: collection,
+        },
+        &signer_seeds,
+    ))?;
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
 pub fn mint_nft<'a>(
@@ -151,11 +718,14 @@ pub fn mint_nft<'a>(
     symbol: String,
     uri: String,
     weight: u64,
+    metal: Metal,
     discriminant: u64,
     mint_signer_seeds: &[&[&[u8]]],
     nft_manager: &mut NFTManager,
     mint: AccountInfo<'a>,
     destination: AccountInfo<'a>,
+    collection: AccountInfo<'a>,
+    collection_bump: u8,
     token_program: AccountInfo<'a>,
     system_program: AccountInfo<'a>,
     payer: AccountInfo<'a>,
@@ -164,6 +734,17 @@ pub fn mint_nft<'a>(
 
     require_gte!(weight, 0, NFTManagerError::InvalidWeight);
 
+    initialize_group_member(
+        mint.clone(),
+        collection,
+        token_program.clone(),
+        system_program.clone(),
+        payer.clone(),
+        mint_signer_seeds,
+        nft_manager.collection_id.as_bytes(),
+        collection_bump,
+    )?;
+
     let token_metadata = TokenMetadata {
         name: name.clone(),
         symbol: symbol.clone(),
@@ -208,26 +789,157 @@ pub fn mint_nft<'a>(
         let buffer = mint.try_borrow_data()?;
         let state = PodStateWithExtensions::<PodMint>::unpack(&buffer)?;
 
-        let mut token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
-        token_metadata.update(
-            token_interface::spl_token_metadata_interface::state::Field::Key(
-                DISCRIMINANT_KEY.to_string(),
-            ),
-            discriminant.to_string(),
-        );
+        let mut token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        token_metadata.update(
+            token_interface::spl_token_metadata_interface::state::Field::Key(
+                DISCRIMINANT_KEY.to_string(),
+            ),
+            discriminant.to_string(),
+        );
+        token_metadata.update(
+            token_interface::spl_token_metadata_interface::state::Field::Key(METAL_KEY.to_string()),
+            metal.as_str().to_string(),
+        );
+
+        let new_account_len =
+            state.try_get_new_account_len_for_variable_len_extension(&token_metadata)?;
+
+        let required_lamports = Rent::get()?.minimum_balance(new_account_len);
+
+        let current_lamports = mint.lamports();
+
+        (current_lamports, required_lamports)
+    };
+
+    if required_lamports > current_lamports {
+        let lamport_difference = required_lamports - current_lamports;
+        system::transfer(
+            CpiContext::new(
+                system_program,
+                system::Transfer {
+                    from: payer,
+                    to: mint.clone(),
+                },
+            ),
+            lamport_difference,
+        )?;
+    }
+
+    token_interface::token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::TokenMetadataUpdateField {
+                token_program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: mint.clone(),
+            },
+            mint_signer_seeds,
+        ),
+        token_interface::spl_token_metadata_interface::state::Field::Key(
+            DISCRIMINANT_KEY.to_string(),
+        ),
+        discriminant.to_string(),
+    )?;
+
+    token_interface::token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::TokenMetadataUpdateField {
+                token_program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: mint.clone(),
+            },
+            mint_signer_seeds,
+        ),
+        token_interface::spl_token_metadata_interface::state::Field::Key(METAL_KEY.to_string()),
+        metal.as_str().to_string(),
+    )?;
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::MintTo {
+                mint: mint.clone(),
+                to: destination,
+                authority: mint.clone(),
+            },
+            mint_signer_seeds,
+        ),
+        1,
+    )?;
+
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            token_interface::SetAuthority {
+                current_authority: mint.clone(),
+                account_or_mint: mint.clone(),
+            },
+            mint_signer_seeds,
+        ),
+        token_interface::spl_token_2022::instruction::AuthorityType::MintTokens,
+        None,
+    )?;
+
+    Ok(())
+}
 
-        let new_account_len =
-            state.try_get_new_account_len_for_variable_len_extension(&token_metadata)?;
+/// Mints an NFT with its complete metadata in a single instruction. The mint is funded for the
+/// final metadata length up front so the additional keys are written without growing the
+/// account one key at a time.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_nft_with_metadata<'a>(
+    name: String,
+    symbol: String,
+    uri: String,
+    weight: &Weight,
+    metal: Metal,
+    discriminant: u64,
+    mint_signer_seeds: &[&[&[u8]]],
+    nft_manager: &mut NFTManager,
+    mint: AccountInfo<'a>,
+    destination: AccountInfo<'a>,
+    collection: AccountInfo<'a>,
+    collection_bump: u8,
+    token_program: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+) -> Result<()> {
+    nft_manager.increment_discriminant()?;
 
-        let required_lamports = Rent::get()?.minimum_balance(new_account_len);
+    initialize_group_member(
+        mint.clone(),
+        collection.clone(),
+        token_program.clone(),
+        system_program.clone(),
+        payer.clone(),
+        mint_signer_seeds,
+        nft_manager.collection_id.as_bytes(),
+        collection_bump,
+    )?;
 
-        let current_lamports = mint.lamports();
+    let additional_metadata = vec![
+        (DISCRIMINANT_KEY.to_string(), discriminant.to_string()),
+        (METAL_KEY.to_string(), metal.as_str().to_string()),
+        (WEIGHT_KEY.to_string(), weight.milligrams.to_string()),
+        (FINENESS_KEY.to_string(), weight.fineness.to_string()),
+        (COLLECTION_KEY.to_string(), collection.key().to_string()),
+    ];
 
-        (current_lamports, required_lamports)
+    let token_metadata = TokenMetadata {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: additional_metadata.clone(),
+        ..Default::default()
     };
 
+    // Extension header (type + length) followed by the packed metadata
+    let required_len = mint.data_len() + 4 + token_metadata.get_packed_len()?;
+    let required_lamports = Rent::get()?.minimum_balance(required_len);
+    let current_lamports = mint.lamports();
+
     if required_lamports > current_lamports {
-        let lamport_difference = required_lamports - current_lamports;
         system::transfer(
             CpiContext::new(
                 system_program,
@@ -236,26 +948,43 @@ pub fn mint_nft<'a>(
                     to: mint.clone(),
                 },
             ),
-            lamport_difference,
+            required_lamports - current_lamports,
         )?;
     }
 
-    token_interface::token_metadata_update_field(
+    token_interface::token_metadata_initialize(
         CpiContext::new_with_signer(
             token_program.clone(),
-            token_interface::TokenMetadataUpdateField {
+            token_interface::TokenMetadataInitialize {
                 token_program_id: token_program.clone(),
+                mint: mint.clone(),
                 metadata: mint.clone(),
+                mint_authority: mint.clone(),
                 update_authority: mint.clone(),
             },
             mint_signer_seeds,
         ),
-        token_interface::spl_token_metadata_interface::state::Field::Key(
-            DISCRIMINANT_KEY.to_string(),
-        ),
-        discriminant.to_string(),
+        name,
+        symbol,
+        uri,
     )?;
 
+    for (key, value) in additional_metadata {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::TokenMetadataUpdateField {
+                    token_program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: mint.clone(),
+                },
+                mint_signer_seeds,
+            ),
+            token_interface::spl_token_metadata_interface::state::Field::Key(key),
+            value,
+        )?;
+    }
+
     token_interface::mint_to(
         CpiContext::new_with_signer(
             token_program.clone(),
@@ -285,13 +1014,89 @@ pub fn mint_nft<'a>(
     Ok(())
 }
 
+/// Creates an NFT mint at its PDA with the extensions `MintNFT` initializes through account constraints.
+pub fn create_nft_mint<'a>(
+    mint: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    mint_signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::MetadataPointer,
+        ExtensionType::GroupMemberPointer,
+        ExtensionType::MintCloseAuthority,
+    ])?;
+
+    system::create_account(
+        CpiContext::new_with_signer(
+            system_program,
+            system::CreateAccount {
+                from: payer,
+                to: mint.clone(),
+            },
+            mint_signer_seeds,
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
+
+    token_interface::metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            token_interface::MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(mint.key()),
+        Some(mint.key()),
+    )?;
+
+    token_interface::group_member_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            token_interface::GroupMemberPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(mint.key()),
+        Some(mint.key()),
+    )?;
+
+    token_interface::mint_close_authority_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            token_interface::MintCloseAuthorityInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(&mint.key()),
+    )?;
+
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            token_program,
+            token_interface::InitializeMint2 { mint: mint.clone() },
+        ),
+        0,
+        &mint.key(),
+        Some(&mint.key()),
+    )?;
+
+    Ok(())
+}
+
 #[inline(always)]
 #[allow(clippy::too_many_arguments)]
 pub fn update_metadata<'a>(
     token_program: AccountInfo<'a>,
     mint: AccountInfo<'a>,
     mint_signer_seeds: &[&[&[u8]]],
-    weight: u64,
+    weight: &Weight,
     name: &str,
     symbol: &str,
     uri: &str,
@@ -308,7 +1113,21 @@ pub fn update_metadata<'a>(
             mint_signer_seeds,
         ),
         token_interface::spl_token_metadata_interface::state::Field::Key(WEIGHT_KEY.to_string()),
-        weight.to_string(),
+        weight.milligrams.to_string(),
+    )?;
+
+    token_interface::token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::TokenMetadataUpdateField {
+                token_program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: mint.clone(),
+            },
+            mint_signer_seeds,
+        ),
+        token_interface::spl_token_metadata_interface::state::Field::Key(FINENESS_KEY.to_string()),
+        weight.fineness.to_string(),
     )?;
 
     token_interface::token_metadata_update_field(
@@ -379,7 +1198,7 @@ pub fn update_metadata_standard<'a>(
     token_program: AccountInfo<'a>,
     payer: AccountInfo<'a>,
     collection: Pubkey,
-    weight: u64,
+    weight: &Weight,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     let (current_lamports, required_lamports) = {
@@ -392,7 +1211,14 @@ pub fn update_metadata_standard<'a>(
             token_interface::spl_token_metadata_interface::state::Field::Key(
                 WEIGHT_KEY.to_string(),
             ),
-            weight.to_string(),
+            weight.milligrams.to_string(),
+        );
+
+        token_metadata.update(
+            token_interface::spl_token_metadata_interface::state::Field::Key(
+                FINENESS_KEY.to_string(),
+            ),
+            weight.fineness.to_string(),
         );
 
         token_metadata.update(
@@ -440,7 +1266,21 @@ pub fn update_metadata_standard<'a>(
             signer_seeds,
         ),
         token_interface::spl_token_metadata_interface::state::Field::Key(WEIGHT_KEY.to_string()),
-        weight.to_string(),
+        weight.milligrams.to_string(),
+    )?;
+
+    token_interface::token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::TokenMetadataUpdateField {
+                token_program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: mint.clone(),
+            },
+            signer_seeds,
+        ),
+        token_interface::spl_token_metadata_interface::state::Field::Key(FINENESS_KEY.to_string()),
+        weight.fineness.to_string(),
     )?;
 
     token_interface::token_metadata_update_field(
@@ -462,6 +1302,87 @@ pub fn update_metadata_standard<'a>(
     Ok(())
 }
 
+/// Sets additional metadata keys on an NFT, funding the mint for the longer metadata first.
+pub fn update_additional_metadata<'a>(
+    mint: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    fields: Vec<(String, String)>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    update_metadata_fields(
+        mint,
+        system_program,
+        token_program,
+        payer,
+        fields
+            .into_iter()
+            .map(|(key, value)| (Field::Key(key), value))
+            .collect(),
+        signer_seeds,
+    )
+}
+
+/// Sets metadata fields on an NFT, funding the mint for the longer metadata first.
+pub fn update_metadata_fields<'a>(
+    mint: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    fields: Vec<(Field, String)>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (current_lamports, required_lamports) = {
+        let buffer = mint.try_borrow_data()?;
+        let state = PodStateWithExtensions::<PodMint>::unpack(&buffer)?;
+
+        let mut token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        for (field, value) in fields.iter() {
+            token_metadata.update(field.clone(), value.clone());
+        }
+
+        let new_account_len =
+            state.try_get_new_account_len_for_variable_len_extension(&token_metadata)?;
+
+        (
+            mint.lamports(),
+            Rent::get()?.minimum_balance(new_account_len),
+        )
+    };
+
+    if required_lamports > current_lamports {
+        system::transfer(
+            CpiContext::new(
+                system_program,
+                system::Transfer {
+                    from: payer,
+                    to: mint.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    for (field, value) in fields {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::TokenMetadataUpdateField {
+                    token_program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: mint.clone(),
+                },
+                signer_seeds,
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    Ok(())
+}
+
 pub fn get_metadata(mint: &AccountInfo) -> Result<TokenMetadata> {
     let buffer = mint.try_borrow_data()?;
     let state = PodStateWithExtensions::<PodMint>::unpack(&buffer)?;
@@ -475,7 +1396,10 @@ mod test {
     use super::*;
 
     const PUBLISH_TIME: i64 = 1000000;
-    const WEIGHT: u64 = 10;
+    const WEIGHT: Weight = Weight {
+        milligrams: 10_000,
+        fineness: 9999,
+    };
 
     #[test]
     fn test_all_negative_exponents() {
@@ -501,11 +1425,207 @@ mod test {
             publish_time: PUBLISH_TIME,
         };
 
-        let price_1 = calc_gold_value_in_lamport(gold_p, sol_p, WEIGHT).unwrap();
-        let price_2 = calc_gold_value_in_lamport(gold_p, sol_p, WEIGHT).unwrap();
-
-        println!("Price 1: {}", price_1);
+        let price_1 =
+            calc_metal_value_in_lamport(gold_p, sol_p, &WEIGHT, TROY_OUNCE_MICROGRAMS).unwrap();
+        let price_2 =
+            calc_metal_value_in_lamport(gold_p, sol_p, &WEIGHT, TROY_OUNCE_MICROGRAMS).unwrap();
 
         assert_eq!(price_1, price_2, "Prices should be equal");
     }
+
+    #[test]
+    fn test_value_scales_with_fineness() {
+        let sol_p = Price {
+            price: 100_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+
+        // 3110.3477 USD per troy ounce, i.e. 0.1 USD per milligram
+        let gold_p = Price {
+            price: 31103477,
+            conf: 0,
+            exponent: -4,
+            publish_time: PUBLISH_TIME,
+        };
+
+        let fine = Weight::new(1_000, 10_000).unwrap();
+        let half = Weight::new(1_000, 5_000).unwrap();
+
+        // 1g of pure metal is worth 100 USD, i.e. 1 SOL
+        let fine_value =
+            calc_metal_value_in_lamport(gold_p, sol_p, &fine, TROY_OUNCE_MICROGRAMS).unwrap();
+        let half_value =
+            calc_metal_value_in_lamport(gold_p, sol_p, &half, TROY_OUNCE_MICROGRAMS).unwrap();
+
+        assert_eq!(fine_value, LAMPORTS_PER_SOL);
+        assert_eq!(half_value, LAMPORTS_PER_SOL / 2);
+        assert_eq!(half.fine_milligrams().unwrap(), 500);
+    }
+
+    #[test]
+    fn test_weight_metadata() {
+        let metadata = vec![
+            (WEIGHT_KEY.to_string(), "5000".to_string()),
+            (FINENESS_KEY.to_string(), "9999".to_string()),
+        ];
+        let invalid_fineness = vec![
+            (WEIGHT_KEY.to_string(), "5000".to_string()),
+            (FINENESS_KEY.to_string(), "10001".to_string()),
+        ];
+
+        assert_eq!(
+            validate_weight(&metadata).unwrap(),
+            Weight {
+                milligrams: 5_000,
+                fineness: 9_999,
+            }
+        );
+        assert!(validate_weight(&invalid_fineness).is_err());
+    }
+
+    #[test]
+    fn test_legacy_weight_metadata() {
+        let legacy = vec![(WEIGHT_KEY.to_string(), "283".to_string())];
+        let weight = validate_weight(&legacy).unwrap();
+
+        // 283 legacy units make a troy ounce
+        assert_eq!(
+            weight,
+            Weight {
+                milligrams: 31_103,
+                fineness: MAX_FINENESS,
+            }
+        );
+        // 109.906 mg rounds up
+        assert_eq!(legacy_weight_to_milligrams(1).unwrap(), 110);
+        assert_eq!(legacy_weight_to_milligrams(2).unwrap(), 220);
+
+        let gold_p = Price {
+            price: 2000_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+        let sol_p = Price {
+            price: 200_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+
+        // Still valued as the ounce of pure metal it was minted as, to the nearest milligram
+        let value =
+            calc_metal_value_in_lamport(gold_p, sol_p, &weight, TROY_OUNCE_MICROGRAMS).unwrap();
+        assert_eq!(value, 9_999_846_640);
+    }
+
+    #[test]
+    fn test_usd_value_in_lamport() {
+        let sol_p = Price {
+            price: 200_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+
+        // 100.00 USD at 200 USD per SOL
+        assert_eq!(
+            calc_usd_value_in_lamport(10_000, sol_p).unwrap(),
+            LAMPORTS_PER_SOL / 2
+        );
+    }
+
+    #[test]
+    fn test_metal_value_in_token() {
+        let gold_p = Price {
+            price: 2000_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+
+        // One troy ounce of pure gold at 2000 USD paid with a 6 decimals token
+        let weight = Weight::new(31_103, MAX_FINENESS).unwrap();
+        let amount = calc_metal_value_in_token(gold_p, &weight, 31_103_000, 6).unwrap();
+
+        assert_eq!(amount, 2_000_000_000);
+    }
+
+    #[test]
+    fn test_price_deviation_within_bound() {
+        let primary = Price {
+            price: 2000_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+        let secondary = Price {
+            price: 2_010_000,
+            conf: 0,
+            exponent: -3,
+            publish_time: PUBLISH_TIME,
+        };
+
+        assert!(validate_price_deviation(&primary, &secondary, 50).is_ok());
+        assert!(validate_price_deviation(&primary, &secondary, 49).is_err());
+    }
+
+    #[test]
+    fn test_get_provenance() {
+        let mint = Pubkey::new_unique();
+        let original = get_provenance(mint, &[]).unwrap();
+        assert_eq!(original.root, mint);
+        assert_eq!(original.generation, 0);
+
+        let child = original.child(mint).unwrap();
+        let read = get_provenance(Pubkey::new_unique(), &provenance_metadata(&child)).unwrap();
+        assert_eq!(read.parent, mint);
+        assert_eq!(read.root, mint);
+        assert_eq!(read.generation, 1);
+    }
+
+    #[test]
+    fn test_validate_fractions() {
+        assert!(validate_fractions(100, &[10; 10]).is_ok());
+        assert!(validate_fractions(100, &[50, 49]).is_err());
+        assert!(validate_fractions(100, &[100, 0]).is_err());
+    }
+
+    #[test]
+    fn test_price_confidence_within_bound() {
+        let price = Price {
+            price: 2989990,
+            conf: 1173,
+            exponent: -3,
+            publish_time: PUBLISH_TIME,
+        };
+
+        // 1173 / 2989990 is roughly 4 bps
+        assert!(validate_price_confidence(&price, 5).is_ok());
+        assert!(validate_price_confidence(&price, 3).is_err());
+    }
+
+    #[test]
+    fn test_price_confidence_wider_than_price() {
+        let price = Price {
+            price: 100,
+            conf: 150,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+        };
+
+        assert!(validate_price_confidence(&price, 9_999).is_err());
+    }
+
+    #[test]
+    fn test_metal_defaults_to_gold() {
+        let legacy = vec![(WEIGHT_KEY.to_string(), "10".to_string())];
+        let silver = vec![(METAL_KEY.to_string(), "silver".to_string())];
+
+        assert_eq!(get_metal(&legacy).unwrap(), Metal::Gold);
+        assert_eq!(get_metal(&silver).unwrap(), Metal::Silver);
+        assert!(validate_metal(&silver, Metal::Gold).is_err());
+    }
 }
@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   const [feesCollectorPda] = anchor.web3.PublicKey.findProgramAddressSync(
@@ -17,7 +17,10 @@ async function main() {
 
   let txSig = await nftManagerProgram.methods
     .adminWithdrawFees(new anchor.BN(balance / 10 - rentExempt))
-    .accounts({ recipient: nftManagerProgram.provider.publicKey })
+    .accountsPartial({
+      nftManager,
+      recipient: nftManagerProgram.provider.publicKey,
+    })
     .rpc();
 
   console.log(`Transaction: ${txSig}`);
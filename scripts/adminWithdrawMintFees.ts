@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   const [nftManagerPda] = anchor.web3.PublicKey.findProgramAddressSync(
@@ -21,7 +21,7 @@ async function main() {
 
   let txSig = await nftManagerProgram.methods
     .adminWithdrawMintFees(new anchor.BN(balance / 100 - rentExempt))
-    .accounts({ recipient: nftManagerPda })
+    .accountsPartial({ nftManager, recipient: nftManagerPda })
     .rpc();
 
   console.log(`Transaction: ${txSig}`);
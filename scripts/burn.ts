@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 
-import { goldPriceFeed, nftManagerProgram, solPriceFeed } from ".";
+import { goldAssetClass, nftManager, nftManagerProgram } from ".";
 
 async function main() {
   const discriminant = new anchor.BN(12);
@@ -15,7 +15,9 @@ async function main() {
     const tx = await nftManagerProgram.methods
       .burnNft(discriminant)
       .accountsPartial({
+        nftManager,
         mint: mintPda,
+        assetClass: goldAssetClass,
       })
       .rpc();
     console.log("Transaction signature:", tx);
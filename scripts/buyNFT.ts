@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram, solPriceFeed, wallet } from ".";
+import { nftManager, nftManagerProgram, solPriceFeed, wallet } from ".";
 
 async function main() {
   const buyNftEventId = nftManagerProgram.addEventListener(
@@ -38,6 +38,7 @@ async function main() {
   let txSig = await nftManagerProgram.methods
     .buyNft(discriminant)
     .accountsPartial({
+      nftManager,
       mint: mintPda,
       seller: listing.account.owner,
       solPriceUpdate: solPriceFeed,
@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { IdlEvents } from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   type EventType = IdlEvents<typeof nftManagerProgram.idl>;
@@ -30,6 +30,7 @@ async function main() {
 
       const tx = await nftManagerProgram.methods
         .finalizeMintNft(buyNftEvent.discriminant)
+        .accountsPartial({ nftManager })
         .rpc();
       console.log("Finalize transaction signature:", tx);
     }
@@ -1,11 +1,12 @@
 import * as anchor from "@coral-xyz/anchor";
 
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   try {
     const tx = await nftManagerProgram.methods
       .finalizeMintNft(new anchor.BN(3))
+      .accountsPartial({ nftManager })
       .rpc();
     console.log("Transaction signature:", tx);
   } catch (error) {
@@ -1,7 +1,10 @@
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
-  let txSig = await nftManagerProgram.methods.finalizeOwnershipTransfer().rpc();
+  let txSig = await nftManagerProgram.methods
+    .finalizeOwnershipTransfer()
+    .accountsPartial({ nftManager })
+    .rpc();
 
   console.log(`Transaction: ${txSig}`);
 }
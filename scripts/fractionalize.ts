@@ -1,6 +1,12 @@
 import * as anchor from "@coral-xyz/anchor";
 
-import { goldPriceFeed, nftManagerProgram, solPriceFeed } from ".";
+import {
+  goldAssetClass,
+  goldPriceFeed,
+  nftManager,
+  nftManagerProgram,
+  solPriceFeed,
+} from ".";
 
 async function main() {
   const WEIGHT = new anchor.BN(10);
@@ -8,6 +14,17 @@ async function main() {
   const currentMintDiscriminant = new anchor.BN(1);
   const partAWeight = new anchor.BN(3);
   const partBWeight = new anchor.BN(3);
+  const FINENESS = 9999;
+  const MAX_LAMPORTS = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+
+  // The NFT is an original bar, so it is the root of its own lineage
+  const [mintPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("mintt"),
+      currentMintDiscriminant.toArrayLike(Buffer, "le", 8),
+    ],
+    nftManagerProgram.programId
+  );
 
   const mintEventId = nftManagerProgram.addEventListener(
     "fractionalizeNftEvent",
@@ -20,27 +37,38 @@ async function main() {
     const fractionalizeNftIx = await nftManagerProgram.methods
       .fractionalizeNft({
         discriminant: currentMintDiscriminant,
-        partA: {
-          name: "Part A",
-          symbol: "PA",
-          uri: "https://parta.com",
-          weight: partAWeight,
-        },
-        partB: {
-          name: "Part B",
-          symbol: "PB",
-          uri: "https://partb.com",
-          weight: partBWeight,
-        },
+        parts: [
+          {
+            name: "Part A",
+            symbol: "PA",
+            uri: "https://parta.com",
+            weight: partAWeight,
+            fineness: FINENESS,
+            metal: { gold: {} },
+          },
+          {
+            name: "Part B",
+            symbol: "PB",
+            uri: "https://partb.com",
+            weight: partBWeight,
+            fineness: FINENESS,
+            metal: { gold: {} },
+          },
+        ],
+        maxLamports: MAX_LAMPORTS,
       })
-      .accounts({
-        goldPriceUpdate: goldPriceFeed,
+      .accountsPartial({
+        nftManager,
+        metalPriceUpdate: goldPriceFeed,
         solPriceUpdate: solPriceFeed,
+        assetClass: goldAssetClass,
+        rootMint: mintPda,
       })
       .instruction();
 
     const finalizeFractionalizeNftIx = await nftManagerProgram.methods
       .finalizeFractionalizeNft(currentMintDiscriminant)
+      .accountsPartial({ nftManager })
       .instruction();
 
     const tx = new anchor.web3.Transaction()
@@ -49,3 +49,59 @@ export const solPriceFeed = new anchor.web3.PublicKey(SOL_PRICE_FEED_ADDR_STR);
 export const goldPriceFeed = new anchor.web3.PublicKey(
   GOLD_PRICE_FEED_ADDR_STR
 );
+
+export const goldAssetClass = anchor.web3.PublicKey.findProgramAddressSync(
+  [
+    Uint8Array.from(
+      JSON.parse(
+        nftManagerProgram.idl.constants.find(
+          (constant) => constant.name === "assetClassTag"
+        ).value
+      )
+    ),
+    Buffer.from("gold"),
+  ],
+  nftManagerProgram.programId
+)[0];
+
+export const nftManager = anchor.web3.PublicKey.findProgramAddressSync(
+  [
+    Uint8Array.from(
+      JSON.parse(
+        nftManagerProgram.idl.constants.find(
+          (constant) => constant.name === "nftManagerTag"
+        ).value
+      )
+    ),
+  ],
+  nftManagerProgram.programId
+)[0];
+
+export function barRecord(serial: string) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Uint8Array.from(
+        JSON.parse(
+          nftManagerProgram.idl.constants.find(
+            (constant) => constant.name === "barRecordTag"
+          ).value
+        )
+      ),
+      Buffer.from(serial),
+    ],
+    nftManagerProgram.programId
+  )[0];
+}
+
+export const buyback = anchor.web3.PublicKey.findProgramAddressSync(
+  [
+    Uint8Array.from(
+      JSON.parse(
+        nftManagerProgram.idl.constants.find(
+          (constant) => constant.name === "buybackTag"
+        ).value
+      )
+    ),
+  ],
+  nftManagerProgram.programId
+)[0];
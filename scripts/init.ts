@@ -1,27 +1,85 @@
+import * as anchor from "@coral-xyz/anchor";
 import { web3 } from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram, wallet2 } from ".";
 
 async function main() {
   try {
-    const createColIx = await nftManagerProgram.methods
-      .createCollection({
-        name: "JPG NFT Collection",
-        symbol: "JPG",
-        uri: "https://arweave.net/123",
-      })
-      .instruction();
     const initIx = await nftManagerProgram.methods
       .initializeNftManager({
         fractionalizeFee: 150,
         sellFee: 250,
+        mintPremiumBps: 150,
+        fabricationFee: new anchor.BN(1_000_000),
+        mergeFee: new anchor.BN(500_000),
+        cancelRefundBps: 5_000,
+        solFeedId: Array.from(
+          Buffer.from(
+            "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d",
+            "hex"
+          )
+        ),
+        maxAge: new anchor.BN(259_200),
+        maxConfBps: 200,
+        priceUpdateOwners: [
+          new web3.PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ"),
+        ],
+        maxDeviationBps: 100,
       })
       .instruction();
 
-    const initTx = new web3.Transaction().add(createColIx).add(initIx);
+    const createGoldAssetClassIx = await nftManagerProgram.methods
+      .createAssetClass({
+        metal: { gold: {} },
+        feedId: Array.from(
+          Buffer.from(
+            "765d2ba906dbc32ca17cc11f5310a89e9ee1f6420508c63861f2f8ba4ee34bb2",
+            "hex"
+          )
+        ),
+        priceUnitMicrograms: new anchor.BN(31_103_477),
+        enabled: true,
+      })
+      .instruction();
+
+    const initReserveLedgerIx = await nftManagerProgram.methods
+      .initializeReserveLedger({ opening: [] })
+      .instruction();
+
+    const initGoldAttestationIx = await nftManagerProgram.methods
+      .initializeAttestation({
+        metal: { gold: {} },
+        auditor: wallet2.publicKey,
+        maxAge: new anchor.BN(2_592_000),
+      })
+      .instruction();
+
+    const initBarRegistryIx = await nftManagerProgram.methods
+      .initializeBarRegistry(wallet2.publicKey)
+      .instruction();
+
+    const initTx = new web3.Transaction()
+      .add(initIx)
+      .add(createGoldAssetClassIx)
+      .add(initReserveLedgerIx)
+      .add(initGoldAttestationIx)
+      .add(initBarRegistryIx);
 
     const initTxSig = await nftManagerProgram.provider.sendAndConfirm(initTx);
 
     console.log("Transaction signature:", initTxSig);
+
+    // The collection seeds are read from the manager, so it must exist first
+    const createColTxSig = await nftManagerProgram.methods
+      .createCollection({
+        name: "JPG NFT Collection",
+        symbol: "JPG",
+        uri: "https://arweave.net/123",
+        maxSize: 100_000,
+      })
+      .accountsPartial({ nftManager })
+      .rpc();
+
+    console.log("Transaction signature:", createColTxSig);
   } catch (error) {
     console.error("Error:", error);
   }
@@ -1,9 +1,9 @@
-import { nftManagerProgram, wallet } from ".";
+import { nftManager, nftManagerProgram, wallet } from ".";
 
 async function main() {
   let txSig = await nftManagerProgram.methods
     .initiailizeOwnershipTransfer()
-    .accounts({ newOwner: wallet.publicKey })
+    .accountsPartial({ nftManager, newOwner: wallet.publicKey })
     .rpc();
 
   console.log(`Transaction: ${txSig}`);
@@ -0,0 +1,47 @@
+import { nftManagerProgram } from ".";
+
+// Mint, weight and bump
+const LEGACY_MINT_DATA_LEN = 8 + 32 + 8 + 1;
+
+// Mint and weight, then name, symbol and uri, then the bump
+function isLegacyFractionalizeData(data: Buffer): boolean {
+  let offset = 8 + 32 + 8;
+  for (let i = 0; i < 3; i++) {
+    if (offset + 4 > data.length) {
+      return false;
+    }
+    offset += 4 + data.readUInt32LE(offset);
+  }
+
+  return offset + 1 === data.length;
+}
+
+// Lists finalize accounts the upgraded program can no longer read. They have to
+// be finalized with the deployed program before upgrading.
+async function main() {
+  const connection = nftManagerProgram.provider.connection;
+  const coder = nftManagerProgram.coder.accounts;
+
+  const mintData = await connection.getProgramAccounts(
+    nftManagerProgram.programId,
+    { filters: [{ memcmp: coder.memcmp("FinalizeMintData") }] }
+  );
+  const fractionalizeData = await connection.getProgramAccounts(
+    nftManagerProgram.programId,
+    { filters: [{ memcmp: coder.memcmp("FinalizeFractionalizeData") }] }
+  );
+
+  const legacy = [
+    ...mintData.filter(
+      ({ account }) => account.data.length === LEGACY_MINT_DATA_LEN
+    ),
+    ...fractionalizeData.filter(({ account }) =>
+      isLegacyFractionalizeData(account.data)
+    ),
+  ];
+
+  legacy.forEach(({ pubkey }) => console.log(pubkey.toBase58()));
+  console.log(`${legacy.length} legacy finalize accounts`);
+}
+
+main().catch(console.error);
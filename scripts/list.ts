@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   const discriminant = new anchor.BN(17);
@@ -16,7 +16,7 @@ async function main() {
 
   let txSig = await nftManagerProgram.methods
     .listNft({ discriminant, price: new anchor.BN(10_000) })
-    .accountsPartial({ mint: mintPda })
+    .accountsPartial({ nftManager, mint: mintPda })
     .rpc();
 
   console.log(`Transaction: ${txSig}`);
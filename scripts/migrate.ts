@@ -0,0 +1,51 @@
+import * as anchor from "@coral-xyz/anchor";
+import { web3 } from "@coral-xyz/anchor";
+import { nftManagerProgram } from ".";
+
+// Brings a deployment initialized before the current layout up to date
+async function main() {
+  try {
+    const migrateIx = await nftManagerProgram.methods
+      .migrateNftManager()
+      .instruction();
+
+    const initOracleConfigIx = await nftManagerProgram.methods
+      .initializeOracleConfig({
+        solFeedId: Array.from(
+          Buffer.from(
+            "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d",
+            "hex"
+          )
+        ),
+        maxAge: new anchor.BN(259_200),
+        maxConfBps: 200,
+        priceUpdateOwners: [
+          new web3.PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ"),
+        ],
+        maxDeviationBps: 100,
+      })
+      .instruction();
+
+    const initFinalizeIndexIx = await nftManagerProgram.methods
+      .initializeFinalizeIndex()
+      .instruction();
+
+    const initReserveLedgerIx = await nftManagerProgram.methods
+      .initializeReserveLedger({ opening: [] })
+      .instruction();
+
+    const txSig = await nftManagerProgram.provider.sendAndConfirm(
+      new web3.Transaction()
+        .add(migrateIx)
+        .add(initOracleConfigIx)
+        .add(initFinalizeIndexIx)
+        .add(initReserveLedgerIx)
+    );
+
+    console.log("Transaction signature:", txSig);
+  } catch (error) {
+    console.error("Error:", error);
+  }
+}
+
+main().catch(console.error);
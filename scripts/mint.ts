@@ -1,12 +1,22 @@
 import * as anchor from "@coral-xyz/anchor";
 
-import { goldPriceFeed, nftManagerProgram, solPriceFeed } from ".";
+import {
+  barRecord,
+  goldAssetClass,
+  goldPriceFeed,
+  nftManager,
+  nftManagerProgram,
+  solPriceFeed,
+} from ".";
 
 async function main() {
   const NAME = "100g Gold Bar";
   const SYMBOL = "GOLD";
   const URI = "https://arweave.net/123";
-  const WEIGHT = new anchor.BN(10);
+  const WEIGHT = new anchor.BN(100_000); // milligrams
+  const FINENESS = 9999;
+  const BAR_SERIAL = "AB-000001"; // registered with the same weight and fineness
+  const MAX_LAMPORTS = new anchor.BN(100 * anchor.web3.LAMPORTS_PER_SOL);
 
   const mintEventId = nftManagerProgram.addEventListener(
     "mintNftEvent",
@@ -20,6 +30,7 @@ async function main() {
       );
       nftManagerProgram.methods
         .finalizeMintNft(event.discriminant)
+        .accountsPartial({ nftManager })
         .rpc()
         .then((finalizeTx) => {
           console.log("Finalize transaction signature:", finalizeTx);
@@ -30,11 +41,22 @@ async function main() {
 
   try {
     const tx = await nftManagerProgram.methods
-      .mintNft({ name: NAME, symbol: SYMBOL, uri: URI, weight: WEIGHT })
-      .accounts({
-        goldPriceUpdate: goldPriceFeed,
+      .mintNft({
+        name: NAME,
+        symbol: SYMBOL,
+        uri: URI,
+        weight: WEIGHT,
+        fineness: FINENESS,
+        metal: { gold: {} },
+        maxLamports: MAX_LAMPORTS,
+      })
+      .accountsPartial({
+        nftManager,
+        metalPriceUpdate: goldPriceFeed,
         solPriceUpdate: solPriceFeed,
         recipient: nftManagerProgram.provider.publicKey,
+        assetClass: goldAssetClass,
+        barRecord: barRecord(BAR_SERIAL),
       })
       .rpc();
     console.log("Transaction signature:", tx);
@@ -0,0 +1,20 @@
+import * as anchor from "@coral-xyz/anchor";
+import { nftManagerProgram, wallet2 } from ".";
+
+async function main() {
+  // The auditor signs, the default wallet pays for the transaction
+  let txSig = await nftManagerProgram.methods
+    .postAttestation({
+      metal: { gold: {} },
+      attestedWeight: new anchor.BN(1_000_000_000),
+      reportHash: Array(32).fill(0),
+      timestamp: new anchor.BN(Math.floor(Date.now() / 1000) - 60),
+    })
+    .accounts({ auditor: wallet2.publicKey })
+    .signers([wallet2.payer])
+    .rpc();
+
+  console.log(`Transaction: ${txSig}`);
+}
+
+main().catch(console.error);
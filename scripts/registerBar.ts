@@ -0,0 +1,22 @@
+import * as anchor from "@coral-xyz/anchor";
+import { nftManagerProgram, wallet2 } from ".";
+
+async function main() {
+  // The operator signs and pays for the bar record
+  let txSig = await nftManagerProgram.methods
+    .registerBar({
+      serial: "AB-000001",
+      refiner: "Valcambi",
+      vaultLocation: "Zurich",
+      metal: { gold: {} },
+      grossWeight: new anchor.BN(100_000),
+      fineness: 9999,
+    })
+    .accounts({ signer: wallet2.publicKey })
+    .signers([wallet2.payer])
+    .rpc();
+
+  console.log(`Transaction: ${txSig}`);
+}
+
+main().catch(console.error);
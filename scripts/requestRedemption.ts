@@ -0,0 +1,27 @@
+import * as anchor from "@coral-xyz/anchor";
+import { createHash } from "crypto";
+import { nftManager, nftManagerProgram } from ".";
+
+async function main() {
+  const discriminant = new anchor.BN(17);
+  // Only the hash of the delivery details goes on-chain
+  const deliveryHash = Array.from(
+    createHash("sha256").update("Jane Doe, 1 Main Street").digest()
+  );
+
+  let txSig = await nftManagerProgram.methods
+    .requestRedemption({ discriminant, deliveryHash })
+    .accountsPartial({ nftManager })
+    .rpc();
+
+  console.log(`Transaction: ${txSig}`);
+
+  const accounts = await nftManagerProgram.account.redemptionRequest.all();
+  for (let account of accounts) {
+    console.log(account.publicKey.toBase58());
+    console.log("Mint: ", account.account.mint.toBase58());
+    console.log("Status: ", JSON.stringify(account.account.status));
+  }
+}
+
+main().catch(console.error);
@@ -0,0 +1,61 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import {
+  barRecord,
+  buyback,
+  goldAssetClass,
+  goldPriceFeed,
+  nftManager,
+  nftManagerProgram,
+  solPriceFeed,
+} from ".";
+
+async function main() {
+  const discriminant = new anchor.BN(18);
+  const [mintPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Uint8Array.from(
+        JSON.parse(
+          nftManagerProgram.idl.constants.find(
+            (constant) => constant.name === "mintTag"
+          ).value
+        )
+      ),
+      discriminant.toArrayLike(Buffer, "le", 8),
+    ],
+    nftManagerProgram.programId
+  );
+
+  // 2.5% under spot, at most 5 SOL a day, NFTs are burned
+  let txSig = await nftManagerProgram.methods
+    .setBuybackConfig({
+      spreadBps: 250,
+      dailyLimit: new anchor.BN(5 * anchor.web3.LAMPORTS_PER_SOL),
+      keepInInventory: false,
+    })
+    .accountsPartial({ nftManager, buyback })
+    .rpc();
+
+  console.log(`Set buyback config: ${txSig}`);
+
+  txSig = await nftManagerProgram.methods
+    .sellBackNft({ discriminant, minLamports: new anchor.BN(1) })
+    .accountsPartial({
+      nftManager,
+      metalPriceUpdate: goldPriceFeed,
+      solPriceUpdate: solPriceFeed,
+      mint: mintPda,
+      assetClass: goldAssetClass,
+      buyback,
+      inventoryTokenAccount: null,
+      barRecord: barRecord("BAR-0001"),
+    })
+    .rpc();
+
+  console.log(`Sell back: ${txSig}`);
+
+  const buybackData = await nftManagerProgram.account.buyback.fetch(buyback);
+  console.log("Paid today: ", buybackData.paidToday.toString());
+}
+
+main().catch(console.error);
@@ -1,12 +1,13 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   let txSig = await nftManagerProgram.methods
     .updateFees({
       fee: { sellFee: {} },
-      newFee: 250,
+      newFee: new anchor.BN(250),
     })
+    .accountsPartial({ nftManager })
     .rpc();
 
   console.log(`Transaction: ${txSig}`);
@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   const listingEventId = nftManagerProgram.addEventListener(
@@ -37,6 +37,7 @@ async function main() {
       discriminant,
       newPrice: new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL),
     })
+    .accountsPartial({ nftManager })
     .rpc();
 
   console.log(`Transaction: ${txSig}`);
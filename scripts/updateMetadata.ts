@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { nftManagerProgram } from ".";
+import { nftManager, nftManagerProgram } from ".";
 
 async function main() {
   const buffer64 = Buffer.alloc(8);
@@ -18,6 +18,7 @@ async function main() {
       field: { uri: {} },
       value: "https://arweave.net/123456",
     })
+    .accountsPartial({ nftManager })
     // .accountsPartial({ mint: mintPda })
     .rpc();
 
@@ -6,20 +6,44 @@ import {
   collectionName,
   collectionSymbol,
   collectionUri,
+  collectionMaxSize,
   fractionalizeFee,
   getPda,
   goldPriceUpdateKey,
   createMintMetadata,
   Pda,
   sellFee,
+  mintPremiumBps,
+  fabricationFee,
+  mergeFee,
+  cancelRefundBps,
   solPriceUpdateKey,
   getAdditionMetadata,
   Metadata,
+  goldFeedId,
+  goldPriceUnitMicrograms,
+  fineness,
+  maxLamports,
+  solFeedId,
+  maxAge,
+  maxConfBps,
+  maxDeviationBps,
+  attestationMaxAge,
+  attestedWeight,
+  barRefiner,
+  barVaultLocation,
+  pythReceiverProgramId,
 } from "./utils";
 import {
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
   getMint,
+  getTokenGroupMemberState,
+  getTokenGroupState,
   getTokenMetadata,
   TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 
 describe("nft-manager", () => {
@@ -37,32 +61,124 @@ describe("nft-manager", () => {
     program,
     Pda.MintFeesCollector
   );
+  const [oracleConfigPda, oracleConfigPdaBump] = getPda(
+    program,
+    Pda.OracleConfig
+  );
+  const [goldAssetClassPda, goldAssetClassPdaBump] = getPda(
+    program,
+    Pda.AssetClass,
+    [Buffer.from("gold")]
+  );
+
+  const [finalizeIndexPda] = getPda(program, Pda.FinalizeIndex);
+  const [reserveLedgerPda] = getPda(program, Pda.ReserveLedger);
+  const [goldAttestationPda] = getPda(program, Pda.Attestation, [
+    Buffer.from("gold"),
+  ]);
+
+  const auditor = anchor.web3.Keypair.generate();
+
+  // Registered in before(), never assigned as the mints using it fail
+  let spareBarPda: anchor.web3.PublicKey;
+
+  const registerBar = async (serial: string, grossWeight: anchor.BN) => {
+    await program.methods
+      .registerBar({
+        serial,
+        refiner: barRefiner,
+        vaultLocation: barVaultLocation,
+        metal: { gold: {} },
+        grossWeight,
+        fineness,
+      })
+      .rpc();
+
+    return getPda(program, Pda.BarRecord, [Buffer.from(serial)])[0];
+  };
 
   const newAuthority = anchor.web3.Keypair.generate();
 
   before(async () => {
     // Add your test here.
-    const createCollectionIx = await program.methods
-      .createCollection({
-        name: collectionName,
-        symbol: collectionSymbol,
-        uri: collectionUri,
-      })
-      .instruction();
-
     const initializeNftManagerIx = await program.methods
       .initializeNftManager({
         fractionalizeFee,
         sellFee,
+        mintPremiumBps,
+        fabricationFee,
+        mergeFee,
+        cancelRefundBps,
+        solFeedId,
+        maxAge,
+        maxConfBps,
+        priceUpdateOwners: [pythReceiverProgramId],
+        maxDeviationBps,
+      })
+      .instruction();
+
+    const createAssetClassIx = await program.methods
+      .createAssetClass({
+        metal: { gold: {} },
+        feedId: goldFeedId,
+        priceUnitMicrograms: goldPriceUnitMicrograms,
+        enabled: true,
+      })
+      .instruction();
+
+    const initializeReserveLedgerIx = await program.methods
+      .initializeReserveLedger({ opening: [] })
+      .instruction();
+
+    const initializeAttestationIx = await program.methods
+      .initializeAttestation({
+        metal: { gold: {} },
+        auditor: auditor.publicKey,
+        maxAge: attestationMaxAge,
       })
       .instruction();
 
+    const initializeBarRegistryIx = await program.methods
+      .initializeBarRegistry(program.provider.publicKey)
+      .instruction();
+
     const tx = new anchor.web3.Transaction()
-      .add(createCollectionIx)
-      .add(initializeNftManagerIx);
+      .add(initializeNftManagerIx)
+      .add(createAssetClassIx)
+      .add(initializeReserveLedgerIx)
+      .add(initializeAttestationIx)
+      .add(initializeBarRegistryIx);
 
     await program.provider.sendAndConfirm(tx);
 
+    // The collection seeds are read from the manager, so it must exist first
+    await program.methods
+      .createCollection({
+        name: collectionName,
+        symbol: collectionSymbol,
+        uri: collectionUri,
+        maxSize: collectionMaxSize,
+      })
+      .accountsPartial({ nftManager: nftManagerPda })
+      .rpc();
+
+    // Reports cannot be dated ahead of the cluster clock
+    const blockTime = await program.provider.connection.getBlockTime(
+      await program.provider.connection.getSlot()
+    );
+    await program.methods
+      .postAttestation({
+        metal: { gold: {} },
+        attestedWeight,
+        reportHash: Array(32).fill(1),
+        timestamp: new anchor.BN(blockTime - 100),
+      })
+      .accounts({ auditor: auditor.publicKey })
+      .signers([auditor])
+      .rpc();
+
+    spareBarPda = await registerBar("SPARE-0001", new anchor.BN(10));
+
     // Airdrop SOL to new Authority
     const signature = await program.provider.connection.requestAirdrop(
       newAuthority.publicKey,
@@ -106,6 +222,10 @@ describe("nft-manager", () => {
     );
     expect(nftManagerData.futureAuthority).equals(null),
       "Future Authority is not correct";
+    expect(nftManagerData.collectionId).equals(
+      "",
+      "Collection Id is not correct"
+    );
 
     // Fees Collector Data
     expect(feesCollectorData.bump).equals(
@@ -124,16 +244,77 @@ describe("nft-manager", () => {
       4,
       "Fees Decimals is not correct"
     );
+    expect(feesCollectorData.mintPremiumBps).equals(
+      mintPremiumBps,
+      "Mint Premium is not correct"
+    );
+    expect(feesCollectorData.fabricationFee.eq(fabricationFee)).to.equal(
+      true,
+      "Fabrication Fee is not correct"
+    );
+    expect(feesCollectorData.mergeFee.eq(mergeFee)).to.equal(
+      true,
+      "Merge Fee is not correct"
+    );
+    expect(feesCollectorData.cancelRefundBps).equals(
+      cancelRefundBps,
+      "Cancel Refund Bps is not correct"
+    );
 
     // Mint Fees Collector Data
     expect(mintFeesCollectorData.bump).equals(
       mintFeesCollectorPdaBump,
       "Bump is not correct"
     );
+
+    // Oracle Config Data
+    const oracleConfigData = await program.account.oracleConfig.fetch(
+      oracleConfigPda
+    );
+    expect(oracleConfigData.bump).equals(
+      oracleConfigPdaBump,
+      "Bump is not correct"
+    );
+    expect(oracleConfigData.solFeedId).to.deep.equal(
+      solFeedId,
+      "Sol Feed Id is not correct"
+    );
+    expect(oracleConfigData.maxAge.eq(maxAge)).to.equal(
+      true,
+      "Max Age is not correct"
+    );
+    expect(oracleConfigData.maxConfBps).equals(
+      maxConfBps,
+      "Max Conf Bps is not correct"
+    );
+    expect(oracleConfigData.priceUpdateOwners[0].toBase58()).equals(
+      pythReceiverProgramId.toBase58(),
+      "Price Update Owner is not correct"
+    );
+
+    // Gold Asset Class Data
+    const goldAssetClassData = await program.account.assetClass.fetch(
+      goldAssetClassPda
+    );
+    expect(goldAssetClassData.bump).equals(
+      goldAssetClassPdaBump,
+      "Bump is not correct"
+    );
+    expect(goldAssetClassData.feedId).to.deep.equal(
+      goldFeedId,
+      "Feed Id is not correct"
+    );
+    expect(
+      goldAssetClassData.priceUnitMicrograms.eq(
+        goldPriceUnitMicrograms
+      )
+    ).to.equal(true, "Price Unit Micrograms is not correct");
+    expect(goldAssetClassData.enabled).to.equal(true, "Enabled is not correct");
   });
 
   it("Mint NFT", async () => {
     const weight = new anchor.BN(10);
+    const barRecordPda = await registerBar("BAR-0001", weight);
     const nftManagerData = await program.account.nftManager.fetch(
       nftManagerPda
     );
@@ -147,16 +328,23 @@ describe("nft-manager", () => {
         symbol: createMintMetadata.symbol,
         uri: createMintMetadata.uri,
         weight,
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
       })
-      .accounts({
-        goldPriceUpdate: goldPriceUpdateKey,
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
         solPriceUpdate: solPriceUpdateKey,
         recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
       })
       .instruction();
 
     const finalizeMintNftIx = await program.methods
       .finalizeMintNft(nftManagerData.discriminant)
+      .accountsPartial({ nftManager: nftManagerPda })
       .instruction();
     const tx = new anchor.web3.Transaction()
       .add(mintNftIx)
@@ -190,6 +378,14 @@ describe("nft-manager", () => {
       Metadata.Collection,
       mintMetadata.additionalMetadata
     );
+    const metadataMetal = getAdditionMetadata(
+      Metadata.Metal,
+      mintMetadata.additionalMetadata
+    );
+    const metadataFineness = getAdditionMetadata(
+      Metadata.Fineness,
+      mintMetadata.additionalMetadata
+    );
 
     expect(nftManagerDataAfter.discriminant.eq(new anchor.BN(1))).to.equal(
       true,
@@ -223,6 +419,254 @@ describe("nft-manager", () => {
       collectionPda.toBase58(),
       "Collection is not correct"
     );
+    expect(metadataMetal).to.equal("gold", "Metal is not correct");
+    expect(metadataFineness).to.equal(
+      fineness.toString(),
+      "Fineness is not correct"
+    );
+
+    const ledger = await program.account.reserveLedger.fetch(reserveLedgerPda);
+    expect(ledger.totalMintedWeight.toString()).to.equal(
+      weight.toString(),
+      "Minted weight is not correct"
+    );
+    expect(ledger.liveNftCount.toNumber()).to.equal(
+      1,
+      "Live NFT count is not correct"
+    );
+    expect(ledger.collections[0].collection.toBase58()).to.equal(
+      collectionPda.toBase58(),
+      "Ledger collection is not correct"
+    );
+
+    expect(
+      getAdditionMetadata(Metadata.BarSerial, mintMetadata.additionalMetadata)
+    ).to.equal("BAR-0001", "Bar serial is not correct");
+    const barRecord = await program.account.barRecord.fetch(barRecordPda);
+    expect(barRecord.status).to.deep.equal(
+      { assigned: {} },
+      "Bar status is not correct"
+    );
+    expect(barRecord.backedWeight.toString()).to.equal(
+      weight.toString(),
+      "Backed weight is not correct"
+    );
+
+    const groupMember = getTokenGroupMemberState(mint);
+    const group = getTokenGroupState(
+      await getMint(
+        program.provider.connection,
+        collectionPda,
+        "processed",
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    expect(groupMember.group.toBase58()).to.equal(
+      collectionPda.toBase58(),
+      "Group is not correct"
+    );
+    expect(groupMember.memberNumber).to.equal(
+      group.size,
+      "Member Number is not correct"
+    );
+    expect(group.maxSize).to.equal(
+      BigInt(collectionMaxSize),
+      "Max Size is not correct"
+    );
+  });
+
+  it("Mint NFT fails when price exceeds max lamports", async () => {
+    const mintNftIx = await program.methods
+      .mintNft({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight: new anchor.BN(10),
+        fineness,
+        metal: { gold: {} },
+        maxLamports: new anchor.BN(1),
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: spareBarPda,
+      })
+      .instruction();
+
+    try {
+      await program.provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(mintNftIx)
+      );
+      expect.fail("Mint should fail");
+    } catch (e) {
+      expect(e.message).to.include("SlippageExceeded");
+    }
+  });
+
+  it("Mint NFT fails when weight is not an allowed denomination", async () => {
+    const setDenominationsIx = await program.methods
+      .updateAssetClass({
+        metal: { gold: {} },
+        field: { denominations: [[new anchor.BN(5), new anchor.BN(100)]] },
+      })
+      .accounts({ assetClass: goldAssetClassPda })
+      .instruction();
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(setDenominationsIx)
+    );
+
+    const assetClassData = await program.account.assetClass.fetch(
+      goldAssetClassPda
+    );
+    expect(assetClassData.denominations.length).to.equal(2);
+
+    const mintNftIx = await program.methods
+      .mintNft({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight: new anchor.BN(10),
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: spareBarPda,
+      })
+      .instruction();
+
+    try {
+      await program.provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(mintNftIx)
+      );
+      expect.fail("Mint should fail");
+    } catch (e) {
+      expect(e.message).to.include("WeightNotDenomination");
+    }
+
+    const clearDenominationsIx = await program.methods
+      .updateAssetClass({
+        metal: { gold: {} },
+        field: { denominations: [[]] },
+      })
+      .accounts({ assetClass: goldAssetClassPda })
+      .instruction();
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(clearDenominationsIx)
+    );
+  });
+
+  it("Mint NFT fails when the attested reserve is exceeded", async () => {
+    const connection = program.provider.connection;
+    const ledger = await program.account.reserveLedger.fetch(reserveLedgerPda);
+    const outstanding = ledger.totalMintedWeight.sub(ledger.totalBurnedWeight);
+    const attestation = await program.account.attestation.fetch(
+      goldAttestationPda
+    );
+
+    await program.methods
+      .postAttestation({
+        metal: { gold: {} },
+        attestedWeight: outstanding,
+        reportHash: Array(32).fill(2),
+        timestamp: attestation.timestamp.add(new anchor.BN(1)),
+      })
+      .accounts({ auditor: auditor.publicKey })
+      .signers([auditor])
+      .rpc();
+
+    const mintNftIx = await program.methods
+      .mintNft({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight: new anchor.BN(10),
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: spareBarPda,
+      })
+      .instruction();
+
+    try {
+      await program.provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(mintNftIx)
+      );
+      expect.fail("Mint should fail");
+    } catch (e) {
+      expect(e.message).to.include("ReserveExceeded");
+    }
+
+    // Only the auditor can post reports
+    try {
+      await program.methods
+        .postAttestation({
+          metal: { gold: {} },
+          attestedWeight,
+          reportHash: Array(32).fill(3),
+          timestamp: attestation.timestamp.add(new anchor.BN(2)),
+        })
+        .accounts({ auditor: program.provider.publicKey })
+        .rpc();
+      expect.fail("Post should fail");
+    } catch (e) {
+      expect(e.message).to.include("OnlyAuditorAllowed");
+    }
+
+    const blockTime = await connection.getBlockTime(await connection.getSlot());
+    await program.methods
+      .postAttestation({
+        metal: { gold: {} },
+        attestedWeight,
+        reportHash: Array(32).fill(3),
+        timestamp: new anchor.BN(blockTime),
+      })
+      .accounts({ auditor: auditor.publicKey })
+      .signers([auditor])
+      .rpc();
+
+    const attestationAfter = await program.account.attestation.fetch(
+      goldAttestationPda
+    );
+    expect(attestationAfter.attestedWeight.toString()).to.equal(
+      attestedWeight.toString(),
+      "Attested weight is not correct"
+    );
+  });
+
+  it("Quote Mint", async () => {
+    const quote = await program.methods
+      .quoteMint({
+        weight: new anchor.BN(10),
+        fineness,
+        metal: { gold: {} },
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        assetClass: goldAssetClassPda,
+      })
+      .view();
+
+    expect(quote.lamports.toNumber()).to.be.greaterThan(0);
+    expect(quote.fee.toNumber()).to.be.greaterThan(0);
+    expect(quote.metalPublishTime).to.not.be.null;
   });
 
   it("Fractionalize NFT", async () => {
@@ -239,26 +683,37 @@ describe("nft-manager", () => {
     const fractionalizeNftIx = await program.methods
       .fractionalizeNft({
         discriminant: mintDiscriminant,
-        partA: {
-          name: createMintMetadata.name,
-          symbol: createMintMetadata.symbol,
-          uri: createMintMetadata.uri,
-          weight: partAWeight,
-        },
-        partB: {
-          name: createMintMetadata.name,
-          symbol: createMintMetadata.symbol,
-          uri: createMintMetadata.uri,
-          weight: partBWeight,
-        },
+        parts: [
+          {
+            name: createMintMetadata.name,
+            symbol: createMintMetadata.symbol,
+            uri: createMintMetadata.uri,
+            weight: partAWeight,
+            fineness,
+            metal: { gold: {} },
+          },
+          {
+            name: createMintMetadata.name,
+            symbol: createMintMetadata.symbol,
+            uri: createMintMetadata.uri,
+            weight: partBWeight,
+            fineness,
+            metal: { gold: {} },
+          },
+        ],
+        maxLamports,
       })
-      .accounts({
-        goldPriceUpdate: goldPriceUpdateKey,
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
         solPriceUpdate: solPriceUpdateKey,
+        assetClass: goldAssetClassPda,
+        rootMint: mintPda,
       })
       .instruction();
     const finalizeFractionalizeNftIx = await program.methods
       .finalizeFractionalizeNft(mintDiscriminant)
+      .accountsPartial({ nftManager: nftManagerPda })
       .instruction();
     const tx2 = new anchor.web3.Transaction()
       .add(fractionalizeNftIx)
@@ -380,6 +835,9 @@ describe("nft-manager", () => {
       collectionPda.toBase58(),
       "Collection is not correct"
     );
+    expect(
+      getAdditionMetadata(Metadata.BarSerial, mint2Metadata.additionalMetadata)
+    ).to.equal("BAR-0001", "Bar serial is not carried to the part");
   });
   // ... existing code ...
 
@@ -408,6 +866,7 @@ describe("nft-manager", () => {
     const listNftIx = await program.methods
       .listNft({ discriminant: mintDiscriminant, price })
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
         // collection: collectionPda,
         listing: listingPda,
@@ -449,6 +908,7 @@ describe("nft-manager", () => {
     const buyNftIx = await program.methods
       .buyNft(mintDiscriminant)
       .accountsPartial({
+        nftManager: nftManagerPda,
         buyer: buyer.publicKey,
         seller: program.provider.publicKey,
         mint: mintPda,
@@ -489,6 +949,7 @@ describe("nft-manager", () => {
     const listNftIx = await program.methods
       .listNft({ discriminant: mintDiscriminant, price: initialPrice })
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
         listing: listingPda,
       })
@@ -505,6 +966,7 @@ describe("nft-manager", () => {
         newPrice: updatedPrice,
       })
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
         listing: listingPda,
       })
@@ -533,6 +995,7 @@ describe("nft-manager", () => {
     const delistNftIx = await program.methods
       .delistNft(mintDiscriminant)
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
         listing: listingPda,
       })
@@ -573,6 +1036,7 @@ describe("nft-manager", () => {
         value: updatedMetadata.name,
       })
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
       })
       .instruction();
@@ -585,6 +1049,7 @@ describe("nft-manager", () => {
         value: updatedMetadata.symbol,
       })
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
       })
       .instruction();
@@ -597,6 +1062,7 @@ describe("nft-manager", () => {
         value: updatedMetadata.uri,
       })
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
       })
       .instruction();
@@ -631,19 +1097,30 @@ describe("nft-manager", () => {
   });
 
   it("Burn NFT", async () => {
+    // The part cut from the first NFT, weighing 5 of BAR-0001's 10
     const mintDiscriminant = new anchor.BN(1);
 
     const [mintPda] = getPda(program, Pda.Mint, [
       mintDiscriminant.toArrayLike(Buffer, "le", 8),
     ]);
+    const [barRecordPda] = getPda(program, Pda.BarRecord, [
+      Buffer.from("BAR-0001"),
+    ]);
 
     const burnNftIx = await program.methods
       .burnNft(mintDiscriminant)
       .accountsPartial({
+        nftManager: nftManagerPda,
         mint: mintPda,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
       })
       .instruction();
 
+    const ledgerBefore = await program.account.reserveLedger.fetch(
+      reserveLedgerPda
+    );
+
     await program.provider.sendAndConfirm(
       new anchor.web3.Transaction().add(burnNftIx)
     );
@@ -651,6 +1128,29 @@ describe("nft-manager", () => {
     // Verify mint account was closed
     const mintInfo = await program.provider.connection.getAccountInfo(mintPda);
     expect(mintInfo).to.be.null;
+
+    const ledgerAfter = await program.account.reserveLedger.fetch(
+      reserveLedgerPda
+    );
+    expect(ledgerAfter.liveNftCount.toNumber()).to.equal(
+      ledgerBefore.liveNftCount.toNumber() - 1,
+      "Live NFT count is not correct"
+    );
+    expect(ledgerAfter.totalBurnedWeight.toNumber()).to.be.greaterThan(
+      ledgerBefore.totalBurnedWeight.toNumber(),
+      "Burned weight is not correct"
+    );
+
+    // The other part still holds the bar
+    const barRecord = await program.account.barRecord.fetch(barRecordPda);
+    expect(barRecord.status).to.deep.equal(
+      { assigned: {} },
+      "Bar is released with a part left"
+    );
+    expect(barRecord.backedWeight.toNumber()).to.equal(
+      5,
+      "Backed weight is not correct"
+    );
   });
 
   it("User Withdraw", async () => {
@@ -690,7 +1190,8 @@ describe("nft-manager", () => {
 
     const adminWithdrawFeesIx = await program.methods
       .adminWithdrawFees(withdrawAmount)
-      .accounts({
+      .accountsPartial({
+        nftManager: nftManagerPda,
         recipient: program.provider.publicKey,
       })
       .instruction();
@@ -720,7 +1221,10 @@ describe("nft-manager", () => {
 
     const adminWithdrawMintFeesIx = await program.methods
       .adminWithdrawMintFees(withdrawAmount)
-      .accounts({ recipient: program.provider.publicKey })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        recipient: program.provider.publicKey,
+      })
       .instruction();
 
     await program.provider.sendAndConfirm(
@@ -742,25 +1246,47 @@ describe("nft-manager", () => {
   it("Update Fees", async () => {
     const newFractionalizeFee = 500; // 0.05%
     const newSellFee = 1000; // 0.1%
+    const newMintPremiumBps = 300; // 3%
+    const newFabricationFee = new anchor.BN(2_000_000);
 
     const updateFeesFractionalizeIx = await program.methods
       .updateFees({
         fee: { fractionalizeFee: {} },
-        newFee: newFractionalizeFee,
+        newFee: new anchor.BN(newFractionalizeFee),
       })
+      .accountsPartial({ nftManager: nftManagerPda })
       .instruction();
 
     const updateFeesSellIx = await program.methods
       .updateFees({
         fee: { sellFee: {} },
-        newFee: newSellFee,
+        newFee: new anchor.BN(newSellFee),
+      })
+      .accountsPartial({ nftManager: nftManagerPda })
+      .instruction();
+
+    const updateFeesMintPremiumIx = await program.methods
+      .updateFees({
+        fee: { mintPremiumBps: {} },
+        newFee: new anchor.BN(newMintPremiumBps),
+      })
+      .accountsPartial({ nftManager: nftManagerPda })
+      .instruction();
+
+    const updateFeesFabricationIx = await program.methods
+      .updateFees({
+        fee: { fabricationFee: {} },
+        newFee: newFabricationFee,
       })
+      .accountsPartial({ nftManager: nftManagerPda })
       .instruction();
 
     await program.provider.sendAndConfirm(
       new anchor.web3.Transaction()
         .add(updateFeesFractionalizeIx)
         .add(updateFeesSellIx)
+        .add(updateFeesMintPremiumIx)
+        .add(updateFeesFabricationIx)
     );
 
     const feesCollectorData = await program.account.feesCollector.fetch(
@@ -775,35 +1301,1261 @@ describe("nft-manager", () => {
       newSellFee,
       "Sell Fee is not updated correctly"
     );
+    expect(feesCollectorData.mintPremiumBps).to.equal(
+      newMintPremiumBps,
+      "Mint Premium is not updated correctly"
+    );
+    expect(feesCollectorData.fabricationFee.eq(newFabricationFee)).to.equal(
+      true,
+      "Fabrication Fee is not updated correctly"
+    );
   });
 
-  it("Initialize Transfer Ownership", async () => {
-    const initializeTransferOwnershipIx = await program.methods
-      .initiailizeOwnershipTransfer()
-      .accounts({
-        newOwner: newAuthority.publicKey,
+  it("Update Oracle Config", async () => {
+    const newMaxAge = new anchor.BN(60);
+
+    const updateOracleConfigIx = await program.methods
+      .updateOracleConfig({ maxAge: [newMaxAge] })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(updateOracleConfigIx)
+    );
+
+    const oracleConfigData = await program.account.oracleConfig.fetch(
+      oracleConfigPda
+    );
+
+    expect(oracleConfigData.maxAge.eq(newMaxAge)).to.equal(
+      true,
+      "Max Age is not updated correctly"
+    );
+
+    const restoreOracleConfigIx = await program.methods
+      .updateOracleConfig({ maxAge: [maxAge] })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(restoreOracleConfigIx)
+    );
+  });
+
+  it("Mint quote fails when secondary price deviates", async () => {
+    const [adminPricePda] = getPda(program, Pda.AdminPrice, [
+      Buffer.from(goldFeedId),
+    ]);
+
+    // One dollar gold, far away from the Pyth price
+    const setAdminPriceIx = await program.methods
+      .setAdminPrice({
+        feedId: goldFeedId,
+        price: new anchor.BN(100_000_000),
+        conf: new anchor.BN(0),
+        exponent: -8,
       })
       .instruction();
 
+    const setSecondaryFeedIx = await program.methods
+      .updateAssetClass({
+        metal: { gold: {} },
+        field: { secondaryFeedId: [goldFeedId] },
+      })
+      .accounts({ assetClass: goldAssetClassPda })
+      .instruction();
+
     await program.provider.sendAndConfirm(
-      new anchor.web3.Transaction().add(initializeTransferOwnershipIx)
+      new anchor.web3.Transaction()
+        .add(setAdminPriceIx)
+        .add(setSecondaryFeedIx)
     );
 
-    const nftManagerData = await program.account.nftManager.fetch(
-      nftManagerPda
+    const adminPriceData = await program.account.adminPrice.fetch(
+      adminPricePda
+    );
+    expect(adminPriceData.price.eq(new anchor.BN(100_000_000))).to.equal(
+      true,
+      "Admin price is not set correctly"
     );
 
-    expect(nftManagerData.futureAuthority.toBase58()).to.equal(
-      newAuthority.publicKey.toBase58(),
-      "Future Authority is not set correctly"
+    try {
+      await program.methods
+        .quoteMint({
+          weight: new anchor.BN(10),
+          fineness,
+          metal: { gold: {} },
+        })
+        .accountsPartial({
+          nftManager: nftManagerPda,
+          metalPriceUpdate: goldPriceUpdateKey,
+          metalSecondaryPrice: adminPricePda,
+          solPriceUpdate: solPriceUpdateKey,
+          assetClass: goldAssetClassPda,
+        })
+        .view();
+      expect.fail("Quote should fail");
+    } catch (e) {
+      expect(e.message).to.include("PriceDeviationTooWide");
+    }
+
+    const clearSecondaryFeedIx = await program.methods
+      .updateAssetClass({
+        metal: { gold: {} },
+        field: { secondaryFeedId: [null] },
+      })
+      .accounts({ assetClass: goldAssetClassPda })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(clearSecondaryFeedIx)
     );
   });
 
-  it("Finalize Ownership Transfer", async () => {
-    // Simulate setting the future authority
-    const finalizeOwnershipTransferIx = await program.methods
-      .finalizeOwnershipTransfer()
-      .accounts({ signer: newAuthority.publicKey })
+  it("Create Payment Token", async () => {
+    const paymentMint = await createMint(
+      program.provider.connection,
+      (program.provider as anchor.AnchorProvider).wallet.payer,
+      program.provider.publicKey,
+      null,
+      6
+    );
+
+    const [paymentTokenPda] = getPda(program, Pda.PaymentToken, [
+      paymentMint.toBuffer(),
+    ]);
+    const [paymentVaultPda] = getPda(program, Pda.PaymentVault, [
+      paymentMint.toBuffer(),
+    ]);
+
+    const createPaymentTokenIx = await program.methods
+      .createPaymentToken(true)
+      .accounts({
+        mint: paymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(createPaymentTokenIx)
+    );
+
+    const paymentTokenData = await program.account.paymentToken.fetch(
+      paymentTokenPda
+    );
+
+    expect(paymentTokenData.mint.toBase58()).to.equal(
+      paymentMint.toBase58(),
+      "Payment token mint is not set correctly"
+    );
+    expect(paymentTokenData.enabled).to.equal(true);
+
+    const vault = await program.provider.connection.getAccountInfo(
+      paymentVaultPda
+    );
+    expect(vault.owner.toBase58()).to.equal(TOKEN_PROGRAM_ID.toBase58());
+  });
+
+  it("Batch Mint NFT", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    const weights = [new anchor.BN(100), new anchor.BN(100)];
+    const barRecordPdas = [
+      await registerBar("BAR-0002", weights[0]),
+      await registerBar("BAR-0003", weights[1]),
+    ];
+
+    const remainingAccounts = weights.flatMap((_, i) => {
+      const [mintPda] = getPda(program, Pda.Mint, [
+        nftManagerData.discriminant
+          .add(new anchor.BN(i))
+          .toArrayLike(Buffer, "le", 8),
+      ]);
+      const recipientTokenAccount = getAssociatedTokenAddressSync(
+        mintPda,
+        program.provider.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      return [
+        { pubkey: mintPda, isSigner: false, isWritable: true },
+        { pubkey: recipientTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: barRecordPdas[i], isSigner: false, isWritable: true },
+      ];
+    });
+
+    const batchMintNftIx = await program.methods
+      .batchMintNft({
+        items: weights.map((weight) => ({
+          name: createMintMetadata.name,
+          symbol: createMintMetadata.symbol,
+          uri: createMintMetadata.uri,
+          weight,
+          fineness,
+          metal: { gold: {} },
+        })),
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction()
+        .add(
+          anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+            units: 1_400_000,
+          })
+        )
+        .add(batchMintNftIx)
+    );
+
+    const nftManagerDataAfter = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    expect(
+      nftManagerDataAfter.discriminant.eq(
+        nftManagerData.discriminant.add(new anchor.BN(weights.length))
+      )
+    ).to.equal(true, "Discriminant is not correct");
+
+    const mintMetadata = await getTokenMetadata(
+      program.provider.connection,
+      remainingAccounts[0].pubkey,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(
+      getAdditionMetadata(Metadata.Weight, mintMetadata.additionalMetadata)
+    ).to.equal(weights[0].toString(), "Weight is not correct");
+    expect(
+      getAdditionMetadata(Metadata.BarSerial, mintMetadata.additionalMetadata)
+    ).to.equal("BAR-0002", "Bar serial is not correct");
+  });
+
+  it("Fractionalize NFT into three parts with batch finalize", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    // Last NFT of the batch mint, weighing 100
+    const mintDiscriminant = nftManagerData.discriminant.sub(new anchor.BN(1));
+    const [mintPda] = getPda(program, Pda.Mint, [
+      mintDiscriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+    const [lineagePda] = getPda(program, Pda.Lineage, [mintPda.toBuffer()]);
+    const weights = [new anchor.BN(50), new anchor.BN(30), new anchor.BN(20)];
+
+    const fractionalizeNftIx = await program.methods
+      .fractionalizeNft({
+        discriminant: mintDiscriminant,
+        parts: weights.map((weight) => ({
+          name: createMintMetadata.name,
+          symbol: createMintMetadata.symbol,
+          uri: createMintMetadata.uri,
+          weight,
+          fineness,
+          metal: { gold: {} },
+        })),
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        assetClass: goldAssetClassPda,
+        rootMint: mintPda,
+      })
+      .instruction();
+
+    const remainingAccounts = weights.slice(1).flatMap((_, i) => {
+      const [newMintPda] = getPda(program, Pda.Mint, [
+        nftManagerData.discriminant
+          .add(new anchor.BN(i))
+          .toArrayLike(Buffer, "le", 8),
+      ]);
+      const newTokenAccount = getAssociatedTokenAddressSync(
+        newMintPda,
+        program.provider.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      return [
+        { pubkey: newMintPda, isSigner: false, isWritable: true },
+        { pubkey: newTokenAccount, isSigner: false, isWritable: true },
+      ];
+    });
+
+    const finalizeFractionalizeNftBatchIx = await program.methods
+      .finalizeFractionalizeNftBatch(mintDiscriminant)
+      .remainingAccounts(remainingAccounts)
+      .accountsPartial({ nftManager: nftManagerPda })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction()
+        .add(
+          anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+            units: 1_400_000,
+          })
+        )
+        .add(fractionalizeNftIx)
+        .add(finalizeFractionalizeNftBatchIx)
+    );
+
+    const nftManagerDataAfter = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    expect(
+      nftManagerDataAfter.discriminant.eq(
+        nftManagerData.discriminant.add(new anchor.BN(weights.length - 1))
+      )
+    ).to.equal(true, "Discriminant is not correct");
+
+    for (const [i, weight] of weights.slice(1).entries()) {
+      const newMintMetadata = await getTokenMetadata(
+        program.provider.connection,
+        remainingAccounts[i * 2].pubkey,
+        "processed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(
+        getAdditionMetadata(Metadata.Weight, newMintMetadata.additionalMetadata)
+      ).to.equal(weight.toString(), "Weight is not correct");
+      expect(
+        getAdditionMetadata(Metadata.Parent, newMintMetadata.additionalMetadata)
+      ).to.equal(mintPda.toBase58(), "Parent is not correct");
+      expect(
+        getAdditionMetadata(
+          Metadata.Generation,
+          newMintMetadata.additionalMetadata
+        )
+      ).to.equal("1", "Generation is not correct");
+    }
+
+    const lineageData = await program.account.lineage.fetch(lineagePda);
+    expect(lineageData.root.toBase58()).to.equal(mintPda.toBase58());
+    expect(
+      lineageData.entries.map((entry) => entry.weight.toNumber())
+    ).to.deep.equal([50, 30, 20], "Lineage is not correct");
+  });
+
+  it("Merge NFTs", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    // The two parts minted by the batch finalize, weighing 30 and 20
+    const discriminants = [
+      nftManagerData.discriminant.sub(new anchor.BN(2)),
+      nftManagerData.discriminant.sub(new anchor.BN(1)),
+    ];
+
+    const mergeAccounts = (discriminants: anchor.BN[]) =>
+      discriminants.flatMap((discriminant) => {
+        const [mintPda] = getPda(program, Pda.Mint, [
+          discriminant.toArrayLike(Buffer, "le", 8),
+        ]);
+        const tokenAccount = getAssociatedTokenAddressSync(
+          mintPda,
+          program.provider.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        );
+
+        return [
+          { pubkey: mintPda, isSigner: false, isWritable: true },
+          { pubkey: tokenAccount, isSigner: false, isWritable: true },
+        ];
+      });
+    const remainingAccounts = mergeAccounts(discriminants);
+
+    // Both parts were cut from the same NFT, whose lineage follows the pairs
+    const partMetadata = await getTokenMetadata(
+      program.provider.connection,
+      remainingAccounts[0].pubkey,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    const rootMint = new anchor.web3.PublicKey(
+      getAdditionMetadata(Metadata.Root, partMetadata.additionalMetadata)
+    );
+    const [lineagePda] = getPda(program, Pda.Lineage, [rootMint.toBuffer()]);
+    remainingAccounts.push({
+      pubkey: lineagePda,
+      isSigner: false,
+      isWritable: true,
+    });
+
+    // The first NFT of the batch mint is an original of its own
+    const mixedDiscriminants = [
+      discriminants[0],
+      nftManagerData.discriminant.sub(new anchor.BN(4)),
+    ];
+    const mixedMergeIx = await program.methods
+      .mergeNfts({
+        discriminants: mixedDiscriminants,
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        assetClass: goldAssetClassPda,
+      })
+      .remainingAccounts([
+        ...mergeAccounts(mixedDiscriminants),
+        { pubkey: lineagePda, isSigner: false, isWritable: true },
+      ])
+      .instruction();
+
+    try {
+      await program.provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(mixedMergeIx)
+      );
+      expect.fail("Merge should fail");
+    } catch (e) {
+      expect(e.message).to.include("MixedLineage");
+    }
+
+    const mergeNftsIx = await program.methods
+      .mergeNfts({
+        discriminants,
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        assetClass: goldAssetClassPda,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction()
+        .add(
+          anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+            units: 1_400_000,
+          })
+        )
+        .add(mergeNftsIx)
+    );
+
+    const [newMintPda] = getPda(program, Pda.Mint, [
+      nftManagerData.discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+    const newMintMetadata = await getTokenMetadata(
+      program.provider.connection,
+      newMintPda,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(
+      getAdditionMetadata(Metadata.Weight, newMintMetadata.additionalMetadata)
+    ).to.equal("50", "Weight is not correct");
+    expect(
+      getAdditionMetadata(Metadata.Parent, newMintMetadata.additionalMetadata)
+    ).to.equal(rootMint.toBase58(), "Parent is not correct");
+    expect(
+      getAdditionMetadata(
+        Metadata.Generation,
+        newMintMetadata.additionalMetadata
+      )
+    ).to.equal("1", "Generation is not correct");
+    expect(
+      getAdditionMetadata(
+        Metadata.BarSerial,
+        newMintMetadata.additionalMetadata
+      )
+    ).to.equal("BAR-0003", "Bar serial is not carried to the merged NFT");
+
+    const [barRecordPda] = getPda(program, Pda.BarRecord, [
+      Buffer.from("BAR-0003"),
+    ]);
+    const barRecord = await program.account.barRecord.fetch(barRecordPda);
+    expect(barRecord.backedWeight.toNumber()).to.equal(
+      100,
+      "Backed weight changed on merge"
+    );
+
+    const lineageData = await program.account.lineage.fetch(lineagePda);
+    expect(
+      lineageData.entries.map((entry) => [
+        entry.mint.toBase58(),
+        entry.weight.toNumber(),
+      ])
+    ).to.deep.equal(
+      [
+        [rootMint.toBase58(), 50],
+        [remainingAccounts[0].pubkey.toBase58(), 0],
+        [remainingAccounts[2].pubkey.toBase58(), 0],
+        [newMintPda.toBase58(), 50],
+      ],
+      "Lineage is not correct"
+    );
+
+    const mergedMint = await program.provider.connection.getAccountInfo(
+      remainingAccounts[0].pubkey
+    );
+    expect(mergedMint).to.equal(null, "Merged mint is not closed");
+  });
+
+  it("Deposit and withdraw NFT from gram vault", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    // The merged NFT, weighing 50
+    const discriminant = nftManagerData.discriminant.sub(new anchor.BN(1));
+    const [mintPda] = getPda(program, Pda.Mint, [
+      discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+    const finenessSeed = Buffer.alloc(2);
+    finenessSeed.writeUInt16LE(fineness);
+    const [gramVaultPda] = getPda(program, Pda.GramVault, [
+      Buffer.from("gold"),
+      finenessSeed,
+    ]);
+    const [gramMintPda] = getPda(program, Pda.GramMint, [
+      gramVaultPda.toBuffer(),
+    ]);
+    const [vaultedNftPda] = getPda(program, Pda.VaultedNft, [
+      mintPda.toBuffer(),
+    ]);
+    const gramTokenAccount = getAssociatedTokenAddressSync(
+      gramMintPda,
+      program.provider.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const createGramVaultIx = await program.methods
+      .createGramVault({ metal: { gold: {} }, fineness })
+      .accountsPartial({ assetClass: goldAssetClassPda })
+      .instruction();
+    const depositIx = await program.methods
+      .depositToGramVault(discriminant)
+      .accountsPartial({ nftManager: nftManagerPda, gramVault: gramVaultPda })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(createGramVaultIx).add(depositIx)
+    );
+
+    const gramBalance = await getAccount(
+      program.provider.connection,
+      gramTokenAccount,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(gramBalance.amount.toString()).to.equal(
+      "50",
+      "Gram amount is not correct"
+    );
+    const gramVaultData = await program.account.gramVault.fetch(gramVaultPda);
+    expect(gramVaultData.totalWeight.toNumber()).to.equal(50);
+
+    const withdrawIx = await program.methods
+      .withdrawFromGramVault(discriminant)
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        gramVault: gramVaultPda,
+        depositor: program.provider.publicKey,
+      })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(withdrawIx)
+    );
+
+    const gramBalanceAfter = await getAccount(
+      program.provider.connection,
+      gramTokenAccount,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(gramBalanceAfter.amount.toString()).to.equal("0");
+    const vaultedNft = await program.provider.connection.getAccountInfo(
+      vaultedNftPda
+    );
+    expect(vaultedNft).to.equal(null, "Vaulted NFT is not closed");
+  });
+
+  it("Mint NFT V2", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    const weight = new anchor.BN(10);
+    const barRecordPda = await registerBar("BAR-0004", weight);
+
+    const [mintPda] = getPda(program, Pda.Mint, [
+      nftManagerData.discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+
+    const mintNftV2Ix = await program.methods
+      .mintNftV2({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight,
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
+      })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(mintNftV2Ix)
+    );
+
+    const mintMetadata = await getTokenMetadata(
+      program.provider.connection,
+      mintPda,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    expect(
+      getAdditionMetadata(Metadata.Weight, mintMetadata.additionalMetadata)
+    ).to.equal(weight.toString(), "Weight is not correct");
+    expect(
+      getAdditionMetadata(Metadata.Fineness, mintMetadata.additionalMetadata)
+    ).to.equal(fineness.toString(), "Fineness is not correct");
+    expect(
+      getAdditionMetadata(Metadata.Collection, mintMetadata.additionalMetadata)
+    ).to.equal(collectionPda.toBase58(), "Collection is not correct");
+  });
+
+  it("Cancel Fractionalize", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    // The NFT minted by mint_nft_v2, weighing 10
+    const discriminant = nftManagerData.discriminant.sub(new anchor.BN(1));
+    const [mintPda] = getPda(program, Pda.Mint, [
+      discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+
+    const fractionalizeNftIx = await program.methods
+      .fractionalizeNft({
+        discriminant,
+        parts: [new anchor.BN(4), new anchor.BN(6)].map((weight) => ({
+          name: "Part",
+          symbol: "PRT",
+          uri: "https://part.com",
+          weight,
+          fineness,
+          metal: { gold: {} },
+        })),
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        assetClass: goldAssetClassPda,
+        rootMint: mintPda,
+      })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(fractionalizeNftIx)
+    );
+
+    const cancelFractionalizeIx = await program.methods
+      .cancelFractionalize(discriminant)
+      .accountsPartial({ nftManager: nftManagerPda })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(cancelFractionalizeIx)
+    );
+
+    const mintMetadata = await getTokenMetadata(
+      program.provider.connection,
+      mintPda,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(
+      getAdditionMetadata(Metadata.Weight, mintMetadata.additionalMetadata)
+    ).to.equal("10", "Weight is not restored");
+    expect(mintMetadata.name).to.equal(
+      createMintMetadata.name,
+      "Name is not restored"
+    );
+
+    const [finalizeDataPda] = getPda(program, Pda.FinalizeFractionalizeData, [
+      mintPda.toBuffer(),
+    ]);
+    const finalizeData = await program.provider.connection.getAccountInfo(
+      finalizeDataPda
+    );
+    expect(finalizeData).to.equal(null, "Finalize data is not closed");
+  });
+
+  it("Burn NFT releases its bar", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    // The NFT minted by mint_nft_v2 and restored by the cancel, weighing 10
+    const discriminant = nftManagerData.discriminant.sub(new anchor.BN(1));
+    const [mintPda] = getPda(program, Pda.Mint, [
+      discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+    const [barRecordPda] = getPda(program, Pda.BarRecord, [
+      Buffer.from("BAR-0004"),
+    ]);
+
+    await program.methods
+      .burnNft(discriminant)
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        mint: mintPda,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
+      })
+      .rpc();
+
+    const barRecord = await program.account.barRecord.fetch(barRecordPda);
+    expect(barRecord.status).to.deep.equal(
+      { unassigned: {} },
+      "Bar is not released"
+    );
+    expect(barRecord.backedWeight.toNumber()).to.equal(
+      0,
+      "Backed weight is not cleared"
+    );
+  });
+
+  it("Request, reject and fulfill redemption", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    const discriminant = nftManagerData.discriminant;
+    const weight = new anchor.BN(10);
+    const barRecordPda = await registerBar("BAR-0008", weight);
+    const [mintPda] = getPda(program, Pda.Mint, [
+      discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+    const [redemptionRequestPda] = getPda(program, Pda.RedemptionRequest, [
+      mintPda.toBuffer(),
+    ]);
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      mintPda,
+      program.provider.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await program.methods
+      .mintNftV2({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight,
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
+      })
+      .rpc();
+
+    const requestRedemption = () =>
+      program.methods
+        .requestRedemption({ discriminant, deliveryHash: Array(32).fill(7) })
+        .accountsPartial({ nftManager: nftManagerPda, mint: mintPda })
+        .rpc();
+
+    await requestRedemption();
+
+    let redemptionRequest = await program.account.redemptionRequest.fetch(
+      redemptionRequestPda
+    );
+    expect(redemptionRequest.status).to.deep.equal(
+      { pending: {} },
+      "Redemption is not pending"
+    );
+    expect(
+      (
+        await getAccount(
+          program.provider.connection,
+          ownerTokenAccount,
+          "processed",
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).amount
+    ).to.equal(BigInt(0), "NFT is not escrowed");
+
+    await program.methods
+      .rejectRedemption(discriminant)
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        mint: mintPda,
+        owner: program.provider.publicKey,
+        redemptionRequest: redemptionRequestPda,
+      })
+      .rpc();
+
+    redemptionRequest = await program.account.redemptionRequest.fetch(
+      redemptionRequestPda
+    );
+    expect(redemptionRequest.status).to.deep.equal(
+      { rejected: {} },
+      "Redemption is not rejected"
+    );
+    expect(
+      (
+        await getAccount(
+          program.provider.connection,
+          ownerTokenAccount,
+          "processed",
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).amount
+    ).to.equal(BigInt(1), "NFT is not returned");
+
+    // A rejected request can be made again
+    await requestRedemption();
+
+    const ledgerBefore = await program.account.reserveLedger.fetch(
+      reserveLedgerPda
+    );
+
+    await program.methods
+      .fulfillRedemption(discriminant)
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        mint: mintPda,
+        owner: program.provider.publicKey,
+        redemptionRequest: redemptionRequestPda,
+        barRecord: barRecordPda,
+      })
+      .rpc();
+
+    redemptionRequest = await program.account.redemptionRequest.fetch(
+      redemptionRequestPda
+    );
+    expect(redemptionRequest.status).to.deep.equal(
+      { fulfilled: {} },
+      "Redemption is not fulfilled"
+    );
+    const mintInfo = await program.provider.connection.getAccountInfo(mintPda);
+    expect(mintInfo).to.be.null;
+
+    const barRecord = await program.account.barRecord.fetch(barRecordPda);
+    expect(barRecord.status).to.deep.equal(
+      { retired: {} },
+      "Bar is not retired"
+    );
+
+    const ledgerAfter = await program.account.reserveLedger.fetch(
+      reserveLedgerPda
+    );
+    expect(ledgerAfter.totalBurnedWeight.toString()).to.equal(
+      ledgerBefore.totalBurnedWeight.add(weight).toString(),
+      "Burned weight is not correct"
+    );
+  });
+
+  it("Sell back NFT", async () => {
+    const connection = program.provider.connection;
+    const seller = program.provider.publicKey;
+    const weight = new anchor.BN(10);
+    const [buybackPda] = getPda(program, Pda.Buyback);
+
+    const mintBackedNft = async (serial: string) => {
+      const { discriminant } = await program.account.nftManager.fetch(
+        nftManagerPda
+      );
+      const barRecordPda = await registerBar(serial, weight);
+      const [mintPda] = getPda(program, Pda.Mint, [
+        discriminant.toArrayLike(Buffer, "le", 8),
+      ]);
+
+      await program.methods
+        .mintNftV2({
+          name: createMintMetadata.name,
+          symbol: createMintMetadata.symbol,
+          uri: createMintMetadata.uri,
+          weight,
+          fineness,
+          metal: { gold: {} },
+          maxLamports,
+        })
+        .accountsPartial({
+          nftManager: nftManagerPda,
+          metalPriceUpdate: goldPriceUpdateKey,
+          solPriceUpdate: solPriceUpdateKey,
+          recipient: seller,
+          assetClass: goldAssetClassPda,
+          barRecord: barRecordPda,
+        })
+        .rpc();
+
+      return { discriminant, mintPda, barRecordPda };
+    };
+
+    const setBuybackConfig = (
+      dailyLimit: anchor.BN,
+      keepInInventory: boolean
+    ) =>
+      program.methods
+        .setBuybackConfig({ spreadBps: 250, dailyLimit, keepInInventory })
+        .accountsPartial({ nftManager: nftManagerPda, buyback: buybackPda })
+        .rpc();
+
+    const sellBackNft = (
+      discriminant: anchor.BN,
+      mintPda: anchor.web3.PublicKey,
+      barRecordPda: anchor.web3.PublicKey | null,
+      inventoryTokenAccount: anchor.web3.PublicKey | null
+    ) =>
+      program.methods
+        .sellBackNft({ discriminant, minLamports: new anchor.BN(1) })
+        .accountsPartial({
+          nftManager: nftManagerPda,
+          metalPriceUpdate: goldPriceUpdateKey,
+          solPriceUpdate: solPriceUpdateKey,
+          mint: mintPda,
+          assetClass: goldAssetClassPda,
+          buyback: buybackPda,
+          inventoryTokenAccount,
+          barRecord: barRecordPda,
+        })
+        .rpc();
+
+    // Burned on sale
+    const burned = await mintBackedNft("BAR-0009");
+    await setBuybackConfig(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL), false);
+
+    const ledgerBefore = await program.account.reserveLedger.fetch(
+      reserveLedgerPda
+    );
+
+    await sellBackNft(
+      burned.discriminant,
+      burned.mintPda,
+      burned.barRecordPda,
+      null
+    );
+
+    const mintInfo = await connection.getAccountInfo(burned.mintPda);
+    expect(mintInfo).to.be.null;
+
+    const barRecord = await program.account.barRecord.fetch(
+      burned.barRecordPda
+    );
+    expect(barRecord.status).to.deep.equal(
+      { unassigned: {} },
+      "Bar is not released"
+    );
+
+    const ledgerAfter = await program.account.reserveLedger.fetch(
+      reserveLedgerPda
+    );
+    expect(ledgerAfter.totalBurnedWeight.toString()).to.equal(
+      ledgerBefore.totalBurnedWeight.add(weight).toString(),
+      "Burned weight is not correct"
+    );
+
+    let buyback = await program.account.buyback.fetch(buybackPda);
+    expect(buyback.paidToday.toNumber()).to.be.greaterThan(0);
+
+    // Kept in the inventory and withdrawn by the admin for re-listing
+    const kept = await mintBackedNft("BAR-0010");
+    const inventoryTokenAccount = getAssociatedTokenAddressSync(
+      kept.mintPda,
+      buybackPda,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await setBuybackConfig(buyback.paidToday, true);
+
+    try {
+      await sellBackNft(
+        kept.discriminant,
+        kept.mintPda,
+        null,
+        inventoryTokenAccount
+      );
+      expect.fail("Sell back should fail");
+    } catch (e) {
+      expect(e.message).to.include("BuybackLimitExceeded");
+    }
+
+    await setBuybackConfig(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL), true);
+    await sellBackNft(
+      kept.discriminant,
+      kept.mintPda,
+      null,
+      inventoryTokenAccount
+    );
+
+    expect(
+      (
+        await getAccount(
+          connection,
+          inventoryTokenAccount,
+          "processed",
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).amount
+    ).to.equal(BigInt(1), "NFT is not in the inventory");
+
+    await program.methods
+      .withdrawBuybackInventory(kept.discriminant)
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        mint: kept.mintPda,
+        buyback: buybackPda,
+      })
+      .rpc();
+
+    expect(
+      (
+        await getAccount(
+          connection,
+          getAssociatedTokenAddressSync(
+            kept.mintPda,
+            seller,
+            false,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          "processed",
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).amount
+    ).to.equal(BigInt(1), "NFT is not withdrawn");
+
+    buyback = await program.account.buyback.fetch(buybackPda);
+    expect(buyback.keepInInventory).to.be.true;
+  });
+
+  it("Crank finalize fails before expiry", async () => {
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+    const discriminant = nftManagerData.discriminant;
+    const barRecordPda = await registerBar("BAR-0006", new anchor.BN(10));
+
+    const mintNftIx = await program.methods
+      .mintNft({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight: new anchor.BN(10),
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
+      })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(mintNftIx)
+    );
+
+    const finalizeIndexData = await program.account.finalizeIndex.fetch(
+      finalizeIndexPda
+    );
+    expect(finalizeIndexData.pending.length).to.equal(
+      1,
+      "Pending finalization is not indexed"
+    );
+    expect(finalizeIndexData.pending[0].kind).to.deep.equal({ mint: {} });
+
+    try {
+      await program.methods
+        .crankFinalizeMint(discriminant)
+        .accountsPartial({
+          nftManager: nftManagerPda,
+          payer: program.provider.publicKey,
+        })
+        .rpc();
+      expect.fail("Crank should fail");
+    } catch (e) {
+      expect(e.message).to.include("FinalizeNotOverdue");
+    }
+
+    // Only the payer recorded at mint time can finalize before expiry
+    try {
+      await program.methods
+        .finalizeMintNft(discriminant)
+        .accountsPartial({
+          nftManager: nftManagerPda,
+          signer: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc();
+      expect.fail("Finalize should fail");
+    } catch (e) {
+      expect(e.message).to.include("NotOwner");
+    }
+
+    await program.methods
+      .finalizeMintNft(discriminant)
+      .accountsPartial({ nftManager: nftManagerPda })
+      .rpc();
+
+    const finalizeIndexDataAfter = await program.account.finalizeIndex.fetch(
+      finalizeIndexPda
+    );
+    expect(finalizeIndexDataAfter.pending.length).to.equal(
+      0,
+      "Finalization is not removed from the index"
+    );
+  });
+
+  it("Mint NFT into a second collection", async () => {
+    const collectionId = Buffer.from("coins");
+    const barRecordPda = await registerBar("BAR-0007", new anchor.BN(10));
+    const [coinsManagerPda] = getPda(program, Pda.NftManager, [collectionId]);
+    const [coinsCollectionPda] = getPda(program, Pda.Collection, [
+      collectionId,
+    ]);
+    const [coinsFeesCollectorPda] = getPda(program, Pda.FeesCollector, [
+      collectionId,
+    ]);
+
+    await program.methods
+      .initializeCollectionManager({
+        collectionId: collectionId.toString(),
+        authority: program.provider.publicKey,
+        fractionalizeFee,
+        sellFee,
+        mintPremiumBps: 0,
+        fabricationFee,
+        mergeFee,
+        cancelRefundBps,
+      })
+      .rpc();
+
+    await program.methods
+      .createCollection({
+        name: collectionName,
+        symbol: collectionSymbol,
+        uri: collectionUri,
+        maxSize: collectionMaxSize,
+      })
+      .accountsPartial({ nftManager: coinsManagerPda })
+      .rpc();
+
+    const coinsManagerData = await program.account.nftManager.fetch(
+      coinsManagerPda
+    );
+    const coinsFeesCollectorData = await program.account.feesCollector.fetch(
+      coinsFeesCollectorPda
+    );
+    expect(coinsManagerData.collectionId).to.equal(
+      collectionId.toString(),
+      "Collection Id is not correct"
+    );
+    expect(coinsManagerData.collection.toBase58()).to.equal(
+      coinsCollectionPda.toBase58(),
+      "Collection is not correct"
+    );
+    expect(coinsFeesCollectorData.mintPremiumBps).to.equal(
+      0,
+      "Mint Premium is not correct"
+    );
+
+    const [mintPda] = getPda(program, Pda.Mint, [
+      collectionId,
+      coinsManagerData.discriminant.toArrayLike(Buffer, "le", 8),
+    ]);
+
+    await program.methods
+      .mintNftV2({
+        name: createMintMetadata.name,
+        symbol: createMintMetadata.symbol,
+        uri: createMintMetadata.uri,
+        weight: new anchor.BN(10),
+        fineness,
+        metal: { gold: {} },
+        maxLamports,
+      })
+      .accountsPartial({
+        nftManager: coinsManagerPda,
+        metalPriceUpdate: goldPriceUpdateKey,
+        solPriceUpdate: solPriceUpdateKey,
+        recipient: program.provider.publicKey,
+        assetClass: goldAssetClassPda,
+        barRecord: barRecordPda,
+      })
+      .rpc();
+
+    const mint = await getMint(
+      program.provider.connection,
+      mintPda,
+      "processed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(getTokenGroupMemberState(mint).group.toBase58()).to.equal(
+      coinsCollectionPda.toBase58(),
+      "Group is not correct"
+    );
+  });
+
+  it("Initialize Transfer Ownership", async () => {
+    const initializeTransferOwnershipIx = await program.methods
+      .initiailizeOwnershipTransfer()
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        newOwner: newAuthority.publicKey,
+      })
+      .instruction();
+
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(initializeTransferOwnershipIx)
+    );
+
+    const nftManagerData = await program.account.nftManager.fetch(
+      nftManagerPda
+    );
+
+    expect(nftManagerData.futureAuthority.toBase58()).to.equal(
+      newAuthority.publicKey.toBase58(),
+      "Future Authority is not set correctly"
+    );
+  });
+
+  it("Finalize Ownership Transfer", async () => {
+    // Simulate setting the future authority
+    const finalizeOwnershipTransferIx = await program.methods
+      .finalizeOwnershipTransfer()
+      .accountsPartial({
+        nftManager: nftManagerPda,
+        signer: newAuthority.publicKey,
+      })
       .instruction();
 
     await program.provider.sendAndConfirm(
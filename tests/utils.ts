@@ -11,12 +11,46 @@ export enum Pda {
   Listing,
   ListingTokenAccount,
   UserAccount,
+  OracleConfig,
+  AssetClass,
+  PaymentToken,
+  PaymentVault,
+  AdminPrice,
+  FinalizeIndex,
+  GramVault,
+  GramMint,
+  VaultedNft,
+  Lineage,
+  ReserveLedger,
+  Attestation,
+  BarRegistry,
+  BarRecord,
+  RedemptionRequest,
+  Buyback,
+  FinalizeFractionalizeData,
 }
 
 export enum Metadata {
   Discriminant,
   Collection,
   Weight,
+  Metal,
+  Fineness,
+  Parent,
+  Generation,
+  Root,
+  BarSerial,
+}
+
+export function getConstantSeed(
+  program: Program<NftManager>,
+  name: string
+): Uint8Array {
+  const constant = program.idl.constants.find((c) => c.name === name);
+  if (!constant) {
+    throw new Error(`Constant ${name} not found`);
+  }
+  return Uint8Array.from(JSON.parse(constant.value));
 }
 
 export function getPda(
@@ -27,29 +61,79 @@ export function getPda(
   const seeds = [];
   switch (pda) {
     case Pda.NftManager:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[13].value)));
+      seeds.push(getConstantSeed(program, "nftManagerTag"));
       break;
     case Pda.FeesCollector:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[4].value)));
+      seeds.push(getConstantSeed(program, "feesCollectorTag"));
       break;
     case Pda.MintFeesCollector:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[11].value)));
+      seeds.push(getConstantSeed(program, "mintFeesCollectorTag"));
       break;
     case Pda.Collection:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[1].value)));
+      seeds.push(getConstantSeed(program, "collectionTag"));
       break;
     case Pda.Mint:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[12].value)));
+      seeds.push(getConstantSeed(program, "mintTag"));
       break;
     case Pda.Listing:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[7].value)));
-
+      seeds.push(getConstantSeed(program, "listingTag"));
       break;
     case Pda.ListingTokenAccount:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[8].value)));
+      seeds.push(getConstantSeed(program, "listingTokenAccountTag"));
       break;
     case Pda.UserAccount:
-      seeds.push(Uint8Array.from(JSON.parse(program.idl.constants[15].value)));
+      seeds.push(getConstantSeed(program, "userTag"));
+      break;
+    case Pda.OracleConfig:
+      seeds.push(getConstantSeed(program, "oracleConfigTag"));
+      break;
+    case Pda.AssetClass:
+      seeds.push(getConstantSeed(program, "assetClassTag"));
+      break;
+    case Pda.PaymentToken:
+      seeds.push(getConstantSeed(program, "paymentTokenTag"));
+      break;
+    case Pda.PaymentVault:
+      seeds.push(getConstantSeed(program, "paymentVaultTag"));
+      break;
+    case Pda.AdminPrice:
+      seeds.push(getConstantSeed(program, "adminPriceTag"));
+      break;
+    case Pda.FinalizeIndex:
+      seeds.push(getConstantSeed(program, "finalizeIndexTag"));
+      break;
+    case Pda.GramVault:
+      seeds.push(getConstantSeed(program, "gramVaultTag"));
+      break;
+    case Pda.GramMint:
+      seeds.push(getConstantSeed(program, "gramMintTag"));
+      break;
+    case Pda.VaultedNft:
+      seeds.push(getConstantSeed(program, "vaultedNftTag"));
+      break;
+    case Pda.Lineage:
+      seeds.push(getConstantSeed(program, "lineageTag"));
+      break;
+    case Pda.ReserveLedger:
+      seeds.push(getConstantSeed(program, "reserveLedgerTag"));
+      break;
+    case Pda.Attestation:
+      seeds.push(getConstantSeed(program, "attestationTag"));
+      break;
+    case Pda.BarRegistry:
+      seeds.push(getConstantSeed(program, "barRegistryTag"));
+      break;
+    case Pda.BarRecord:
+      seeds.push(getConstantSeed(program, "barRecordTag"));
+      break;
+    case Pda.RedemptionRequest:
+      seeds.push(getConstantSeed(program, "redemptionRequestTag"));
+      break;
+    case Pda.Buyback:
+      seeds.push(getConstantSeed(program, "buybackTag"));
+      break;
+    case Pda.FinalizeFractionalizeData:
+      seeds.push(getConstantSeed(program, "finalizeFractionalizeDataTag"));
       break;
     default:
       throw new Error("Invalid Pda");
@@ -85,6 +169,36 @@ export function getAdditionMetadata(
           return value;
         }
         break;
+      case Metadata.Metal:
+        if (key === "metal") {
+          return value;
+        }
+        break;
+      case Metadata.Fineness:
+        if (key === "fineness") {
+          return value;
+        }
+        break;
+      case Metadata.Parent:
+        if (key === "parent") {
+          return value;
+        }
+        break;
+      case Metadata.Generation:
+        if (key === "generation") {
+          return value;
+        }
+        break;
+      case Metadata.Root:
+        if (key === "root") {
+          return value;
+        }
+        break;
+      case Metadata.BarSerial:
+        if (key === "bar_serial") {
+          return value;
+        }
+        break;
     }
   }
   throw new Error("Invalid Metadata");
@@ -92,6 +206,7 @@ export function getAdditionMetadata(
 export const collectionName = "Collection";
 export const collectionSymbol = "COL";
 export const collectionUri = "https://collection.com";
+export const collectionMaxSize = 100_000;
 
 const solPriceUpdateStr = "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE";
 const goldPriceUpdateStr = "2uPQGpm8X4ZkxMHxrAW1QuhXcse1AHEgPih6Xp9NuEWW";
@@ -99,8 +214,40 @@ const goldPriceUpdateStr = "2uPQGpm8X4ZkxMHxrAW1QuhXcse1AHEgPih6Xp9NuEWW";
 export const solPriceUpdateKey = new anchor.web3.PublicKey(solPriceUpdateStr);
 export const goldPriceUpdateKey = new anchor.web3.PublicKey(goldPriceUpdateStr);
 
+export const pythReceiverProgramId = new anchor.web3.PublicKey(
+  "rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ"
+);
+
+export const goldFeedId = Array.from(
+  Buffer.from(
+    "765d2ba906dbc32ca17cc11f5310a89e9ee1f6420508c63861f2f8ba4ee34bb2",
+    "hex"
+  )
+);
+export const solFeedId = Array.from(
+  Buffer.from(
+    "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d",
+    "hex"
+  )
+);
+// Micrograms in one troy ounce, the unit Pyth metal prices are quoted in
+export const goldPriceUnitMicrograms = new anchor.BN(31_103_477);
+export const fineness = 9999;
+export const maxLamports = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+export const maxAge = new anchor.BN(259_200);
+export const maxConfBps = 200;
+export const maxDeviationBps = 100;
+export const attestationMaxAge = new anchor.BN(2_592_000);
+export const attestedWeight = new anchor.BN(1_000_000_000);
+export const barRefiner = "Refiner";
+export const barVaultLocation = "Vault A";
+
 export const fractionalizeFee = 150;
 export const sellFee = 250;
+export const mintPremiumBps = 150;
+export const fabricationFee = new anchor.BN(1_000_000);
+export const mergeFee = new anchor.BN(500_000);
+export const cancelRefundBps = 5_000;
 
 export const createMintMetadata: {
   name: string;